@@ -10,4 +10,122 @@ flags = Flaggen
 
 emojis-and-favorites = Emojis und Favoriten
 
-search-for-emojis = Nach Emojis suchen...
\ No newline at end of file
+search-for-emojis = Nach Emojis suchen...
+
+collections = Sammlungen
+new-collection = Neue Sammlung...
+use-as-icon = Als Symbol verwenden
+more-actions = Weitere Aktionen
+remove-from-recent = Aus dem Verlauf entfernen
+
+menu = Menü
+history = Verlauf
+pause-history = Verlauf pausieren
+clear-history = Verlauf löschen
+
+snippets = Schnipsel
+snippet-name = Name
+snippet-text = Text oder Emojis
+save-snippet = Schnipsel speichern
+
+usage-statistics = Nutzungsstatistik
+usage-statistics-local = Die Statistik wird nur lokal gespeichert und nie versendet.
+most-used-week = Diese Woche am häufigsten verwendet
+most-used-month = Diesen Monat am häufigsten verwendet
+usage-by-group = Nutzung nach Gruppe
+no-usage = Noch keine Emojis verwendet
+streaks = Serien
+current-streak = Aktuelle Serie: { $days ->
+    [one] 1 Tag
+   *[other] { $days } Tage
+}
+longest-streak = Längste Serie: { $days ->
+    [one] 1 Tag
+   *[other] { $days } Tage
+}
+export-csv = Als CSV exportieren
+exported-to = Exportiert nach { $path }
+export-failed = Export fehlgeschlagen: { $error }
+no-data-dir = Weder XDG_DATA_HOME noch HOME ist gesetzt
+
+remember-skin-tone = Als Standard merken
+compose-skin-tones = Hautfarbe pro Person
+add-to-compose = Zur Eingabeleiste hinzufügen
+person = Person { $number }
+no-such-variant = Es gibt kein Emoji mit diesen Hautfarben.
+copy = Kopieren
+
+zwj-builder = ZWJ-Sequenzen erstellen
+edit-in-zwj-builder = Als ZWJ-Sequenz bearbeiten
+zwj-pick-component = Emojis auswählen, um sie zu einem zu kombinieren.
+zwj-cannot-combine = { $emoji } kann nicht zu dieser Sequenz hinzugefügt werden.
+zwj-incomplete = Noch kein vollständiges Emoji.
+zwj-complete = Weitere Emojis auswählen oder das Ergebnis kopieren.
+
+flag-composer = Flaggen erstellen
+flag-code = Länder- oder Regionscode
+flag-code-example = Einen Code wie de oder gb-sct eingeben.
+flag-invalid-code = Dies ist kein gültiger Länder- oder Regionscode.
+flag-not-rgi = Diese Flagge ist kein empfohlenes Emoji und wird von den meisten Schriftarten nicht angezeigt.
+
+color-buttons = Farbknöpfe
+add-color-button = Farbknopf hinzufügen
+custom-skin-tone-mode = Eigener Hautfarbenmodus
+reserved-bits = Dieser Hautfarbenmodus setzt reservierte Bits, die ignoriert werden.
+red = Rot
+green = Grün
+blue = Blau
+skin-tone-default = Standardhautfarbe
+skin-tone-light = Hell
+skin-tone-medium-light = Mittelhell
+skin-tone-medium = Mittel
+skin-tone-medium-dark = Mitteldunkel
+skin-tone-dark = Dunkel
+no-skin-tone = Ohne Hautfarbe
+other-skin-tones = Andere Hautfarben
+skin-tone-pair = Genau { $first } und { $second }
+
+skin-tones = Hautfarben
+skin-tones-default-only = Nur Standardhautfarbe
+skin-tones-chosen = Mit den Farbknöpfen gewählte Hautfarben
+skin-tones-grouped = Alle Hautfarben, unter jedem Emoji gruppiert
+match-every-person = Jede Person hat eine gewählte Hautfarbe
+match-any-person = Eine Person hat eine gewählte Hautfarbe
+match-exact = Nur genau gewählte Hautfarben
+
+copy-as = Kopieren als
+format-shortcode = Kurzcode
+format-codepoint = Codepunkte
+format-html-entity = HTML-Entität
+format-rust = Rust
+format-json = JSON und JavaScript
+format-python = Python
+format-url-encoded = URL-kodiert
+format-name = Name
+
+image = Bild
+image-size = { $size } px
+copy-as-image = Als Bild kopieren
+save-as-png = Als PNG speichern
+
+presentation = Darstellung
+presentation-as-listed = Wie aufgelistet
+presentation-emoji = Emoji-Darstellung
+presentation-text = Textdarstellung
+presentation-minimal = Minimal qualifiziert
+
+auto-paste = Nach dem Kopieren einfügen
+paste-chord = Tastenkombination, z. B. Ctrl+Shift+V
+
+text-transform = Text umwandeln
+text-to-transform = Umzuwandelnder Text
+transform-regional-indicators = Regionale Indikatorbuchstaben
+transform-squared = Buchstaben in Quadraten
+transform-negative-squared = Buchstaben in gefüllten Quadraten
+transform-keycaps = Ziffern als Tasten
+transform-clap = Klatschen zwischen Wörtern
+
+export-favorites = Kürzel für andere Anwendungen
+export-favorites-description = Exportiert die Kurzcodes des Verlaufs, der Sammlungen und der Schnipsel, z. B. :tada: für 🎉.
+export-xcompose = XCompose
+export-espanso = espanso
@@ -10,4 +10,122 @@ flags = Flags
 
 emojis-and-favorites = Emojis and Favorites
 
-search-for-emojis = Search for Emojis...
\ No newline at end of file
+search-for-emojis = Search for Emojis...
+
+collections = Collections
+new-collection = New collection...
+use-as-icon = Use as icon
+more-actions = More actions
+remove-from-recent = Remove from recent
+
+menu = Menu
+history = History
+pause-history = Pause history
+clear-history = Clear history
+
+snippets = Snippets
+snippet-name = Name
+snippet-text = Text or emojis
+save-snippet = Save snippet
+
+usage-statistics = Usage statistics
+usage-statistics-local = Statistics are stored locally and never sent anywhere.
+most-used-week = Most used this week
+most-used-month = Most used this month
+usage-by-group = Usage by group
+no-usage = No emojis used yet
+streaks = Streaks
+current-streak = Current streak: { $days ->
+    [one] 1 day
+   *[other] { $days } days
+}
+longest-streak = Longest streak: { $days ->
+    [one] 1 day
+   *[other] { $days } days
+}
+export-csv = Export as CSV
+exported-to = Exported to { $path }
+export-failed = Export failed: { $error }
+no-data-dir = Neither XDG_DATA_HOME nor HOME is set
+
+remember-skin-tone = Remember as default
+compose-skin-tones = Skin tone per person
+add-to-compose = Add to compose bar
+person = Person { $number }
+no-such-variant = There is no emoji with these skin tones.
+copy = Copy
+
+zwj-builder = ZWJ sequence builder
+edit-in-zwj-builder = Edit in ZWJ sequence builder
+zwj-pick-component = Pick emojis to combine them into one.
+zwj-cannot-combine = { $emoji } cannot be added to this sequence.
+zwj-incomplete = Not a complete emoji yet.
+zwj-complete = Pick more emojis or copy the result.
+
+flag-composer = Flag composer
+flag-code = Country or subdivision code
+flag-code-example = Enter a code like de or gb-sct.
+flag-invalid-code = This is not a valid country or subdivision code.
+flag-not-rgi = This flag is not a recommended emoji and most fonts will not show it.
+
+color-buttons = Color buttons
+add-color-button = Add color button
+custom-skin-tone-mode = Custom skin tone mode
+reserved-bits = This skin tone mode sets reserved bits, which are ignored.
+red = Red
+green = Green
+blue = Blue
+skin-tone-default = Default skin tone
+skin-tone-light = Light
+skin-tone-medium-light = Medium-light
+skin-tone-medium = Medium
+skin-tone-medium-dark = Medium-dark
+skin-tone-dark = Dark
+no-skin-tone = No skin tone
+other-skin-tones = Other skin tones
+skin-tone-pair = Exactly { $first } and { $second }
+
+skin-tones = Skin tones
+skin-tones-default-only = Default skin tone only
+skin-tones-chosen = Skin tones chosen with the color buttons
+skin-tones-grouped = All skin tones, grouped under each emoji
+match-every-person = Every person has a chosen skin tone
+match-any-person = Any person has a chosen skin tone
+match-exact = Only exactly chosen skin tones
+
+copy-as = Copy as
+format-shortcode = Shortcode
+format-codepoint = Code points
+format-html-entity = HTML entity
+format-rust = Rust
+format-json = JSON and JavaScript
+format-python = Python
+format-url-encoded = URL-encoded
+format-name = Name
+
+image = Image
+image-size = { $size } px
+copy-as-image = Copy as image
+save-as-png = Save as PNG
+
+presentation = Presentation
+presentation-as-listed = As listed
+presentation-emoji = Emoji presentation
+presentation-text = Text presentation
+presentation-minimal = Minimally qualified
+
+auto-paste = Paste after copying
+paste-chord = Key chord, e.g. Ctrl+Shift+V
+
+text-transform = Text transform
+text-to-transform = Text to transform
+transform-regional-indicators = Regional indicator letters
+transform-squared = Squared letters
+transform-negative-squared = Negative squared letters
+transform-keycaps = Keycap digits
+transform-clap = Claps between words
+
+export-favorites = Shortcuts for other applications
+export-favorites-description = Exports the shortcodes of the history, collections and snippets, e.g. :tada: for 🎉.
+export-xcompose = XCompose
+export-espanso = espanso
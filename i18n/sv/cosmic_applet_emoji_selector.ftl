@@ -11,3 +11,121 @@ flags = Flaggor
 emojis-and-favorites = Emojis och favoriter
 
 search-for-emojis = Sök efter emojis...
+
+collections = Samlingar
+new-collection = Ny samling...
+use-as-icon = Använd som ikon
+more-actions = Fler åtgärder
+remove-from-recent = Ta bort från senaste
+
+menu = Meny
+history = Historik
+pause-history = Pausa historik
+clear-history = Rensa historik
+
+snippets = Textsnuttar
+snippet-name = Namn
+snippet-text = Text eller emojis
+save-snippet = Spara textsnutt
+
+usage-statistics = Användningsstatistik
+usage-statistics-local = Statistiken sparas lokalt och skickas aldrig någonstans.
+most-used-week = Mest använda denna vecka
+most-used-month = Mest använda denna månad
+usage-by-group = Användning per grupp
+no-usage = Inga emojis använda ännu
+streaks = Sviter
+current-streak = Nuvarande svit: { $days ->
+    [one] 1 dag
+   *[other] { $days } dagar
+}
+longest-streak = Längsta svit: { $days ->
+    [one] 1 dag
+   *[other] { $days } dagar
+}
+export-csv = Exportera som CSV
+exported-to = Exporterat till { $path }
+export-failed = Exporten misslyckades: { $error }
+no-data-dir = Varken XDG_DATA_HOME eller HOME är satt
+
+remember-skin-tone = Kom ihåg som standard
+compose-skin-tones = Hudfärg per person
+add-to-compose = Lägg till i sammansättningsfältet
+person = Person { $number }
+no-such-variant = Det finns ingen emoji med dessa hudfärger.
+copy = Kopiera
+
+zwj-builder = Bygg ZWJ-sekvenser
+edit-in-zwj-builder = Redigera som ZWJ-sekvens
+zwj-pick-component = Välj emojis för att kombinera dem till en.
+zwj-cannot-combine = { $emoji } kan inte läggas till i den här sekvensen.
+zwj-incomplete = Ännu ingen fullständig emoji.
+zwj-complete = Välj fler emojis eller kopiera resultatet.
+
+flag-composer = Skapa flaggor
+flag-code = Lands- eller regionskod
+flag-code-example = Ange en kod som de eller gb-sct.
+flag-invalid-code = Detta är ingen giltig lands- eller regionskod.
+flag-not-rgi = Denna flagga är ingen rekommenderad emoji och visas inte av de flesta typsnitt.
+
+color-buttons = Färgknappar
+add-color-button = Lägg till färgknapp
+custom-skin-tone-mode = Eget hudfärgsläge
+reserved-bits = Detta hudfärgsläge sätter reserverade bitar, som ignoreras.
+red = Röd
+green = Grön
+blue = Blå
+skin-tone-default = Standardhudfärg
+skin-tone-light = Ljus
+skin-tone-medium-light = Mellanljus
+skin-tone-medium = Mellan
+skin-tone-medium-dark = Mellanmörk
+skin-tone-dark = Mörk
+no-skin-tone = Ingen hudfärg
+other-skin-tones = Andra hudfärger
+skin-tone-pair = Exakt { $first } och { $second }
+
+skin-tones = Hudfärger
+skin-tones-default-only = Endast standardhudfärg
+skin-tones-chosen = Hudfärger valda med färgknapparna
+skin-tones-grouped = Alla hudfärger, grupperade under varje emoji
+match-every-person = Varje person har en vald hudfärg
+match-any-person = Någon person har en vald hudfärg
+match-exact = Endast exakt valda hudfärger
+
+copy-as = Kopiera som
+format-shortcode = Kortkod
+format-codepoint = Kodpunkter
+format-html-entity = HTML-entitet
+format-rust = Rust
+format-json = JSON och JavaScript
+format-python = Python
+format-url-encoded = URL-kodad
+format-name = Namn
+
+image = Bild
+image-size = { $size } px
+copy-as-image = Kopiera som bild
+save-as-png = Spara som PNG
+
+presentation = Presentation
+presentation-as-listed = Som i listan
+presentation-emoji = Emoji-presentation
+presentation-text = Textpresentation
+presentation-minimal = Minimalt kvalificerad
+
+auto-paste = Klistra in efter kopiering
+paste-chord = Tangentkombination, t.ex. Ctrl+Shift+V
+
+text-transform = Omvandla text
+text-to-transform = Text att omvandla
+transform-regional-indicators = Regionala indikatorbokstäver
+transform-squared = Bokstäver i rutor
+transform-negative-squared = Bokstäver i fyllda rutor
+transform-keycaps = Siffror som tangenter
+transform-clap = Klappar mellan orden
+
+export-favorites = Genvägar för andra program
+export-favorites-description = Exporterar kortkoderna för historiken, samlingarna och textsnuttarna, t.ex. :tada: för 🎉.
+export-xcompose = XCompose
+export-espanso = espanso
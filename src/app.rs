@@ -3,28 +3,42 @@
 
 use std::borrow::Cow;
 use std::collections::{HashMap, VecDeque};
+use std::path::PathBuf;
+use std::time::Duration;
 
-use crate::config::{Annotation, ClickMode, SkinToneMode};
-use crate::config::{Config, CONFIG_VERSION};
+use crate::config::{
+    rgba, Annotation, ClickMode, Collection, ColorButton, GenderMode, HairStyleMode, Presentation,
+    SkinToneFilter, SkinToneMode, Snippet, SKIN_TONE_COLORS,
+};
+use crate::config::{Config, CONFIG_VERSION, MAX_COLLECTIONS};
+use crate::copy_format::{self, CopyFormat};
+use crate::export::ExportFormat;
 #[allow(unused_imports)]
 use crate::fl;
-use crate::{utils, widget_copy};
+use crate::stats::UsageDay;
+use crate::text_transform::Transform;
+use crate::{flag, render, stats, utils, virtual_keyboard, widget_copy};
 use aho_corasick::AhoCorasick;
 use cosmic::app::Core;
+use cosmic::cosmic_config::{self, ConfigGet};
 use cosmic::iced;
+use cosmic::iced::clipboard::dnd::{DndAction, DndEvent, SourceEvent};
+use cosmic::iced::clipboard::mime::AsMimeTypes;
 use cosmic::iced::wayland::popup::{destroy_popup, get_popup};
 use cosmic::iced::window::Id;
 #[allow(unused_imports)]
 use cosmic::iced::{alignment, Alignment, Length};
 use cosmic::iced::{Command, Limits};
+use cosmic::iced_core;
 use cosmic::iced_futures::Subscription;
+use cosmic::iced_runtime::core::clipboard::DndSource;
 use cosmic::iced_runtime::core::window;
 use cosmic::iced_style::application;
 use cosmic::iced_widget::scrollable;
 use cosmic::widget::{self};
-use cosmic::{cosmic_config, iced_core};
 use cosmic::{Apply, Element, Theme};
 use cosmic_time::Timeline;
+use serde::de::DeserializeOwned;
 pub const ID: &str = "dev.dominiccgeh.CosmicAppletEmojiSelector";
 const ICON: &str = ID;
 pub struct Window {
@@ -45,7 +59,125 @@ pub struct Window {
     scrollable_id: widget::Id,
     font_family: cosmic::iced::font::Font,
     emoji_hovered: Option<&'static emojis::Emoji>,
+    snippet_hovered: Option<usize>,
+    snippets_filtered: Vec<usize>,
+    snippet_name: String,
+    snippet_text: String,
+    /// Usage data for the statistics page, stored in [`stats::data_dir`].
+    usage_stats: Vec<UsageDay>,
+    stats_export: Option<Result<PathBuf, String>>,
+    image_export: Option<Result<PathBuf, String>>,
+    favorites_export: Option<Result<PathBuf, String>>,
+    image_size_names: Vec<String>,
+    dragged: Option<&'static emojis::Emoji>,
     text_input_id: widget::Id,
+    selected_collection: Option<usize>,
+    page: Page,
+    collection_name: String,
+    /// The paste chord being edited, only saved once it is valid.
+    paste_chord: String,
+    history_paused: bool,
+    variants_popover: Option<&'static emojis::Emoji>,
+    composer_skin_tones: [emojis::SkinTone; 2],
+    zwj_builder: Option<Vec<&'static emojis::Emoji>>,
+    zwj_rejected: Option<&'static emojis::Emoji>,
+    flag_code: String,
+    transform_text: String,
+    transform: Transform,
+    color_button_edited: Option<usize>,
+    skin_tone_mode_names: Vec<String>,
+    expanded_emoji: Option<&'static emojis::Emoji>,
+    compose: Vec<String>,
+    compose_cursor: usize,
+    compose_undo: Vec<(Vec<String>, usize)>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Page {
+    Emojis,
+    ContextMenu(&'static emojis::Emoji),
+    Menu,
+    Snippets,
+    Stats,
+    ToneComposer(&'static emojis::Emoji),
+    FlagComposer,
+    ColorButtons,
+    TextTransform,
+}
+
+/// Content which is copied by [`Message::EmojiCopy`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum Copyable {
+    Emoji(&'static emojis::Emoji),
+    Text(String),
+}
+
+impl Copyable {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Copyable::Emoji(emoji) => emoji.as_str(),
+            Copyable::Text(text) => text,
+        }
+    }
+}
+
+/// Text offered to the drop target when an emoji is dragged out of the popup.
+struct DragText(String);
+
+impl AsMimeTypes for DragText {
+    fn available(&self) -> Cow<'static, [String]> {
+        Cow::Owned(vec![
+            "text/plain;charset=utf-8".to_string(),
+            "text/plain".to_string(),
+            "UTF8_STRING".to_string(),
+        ])
+    }
+
+    fn as_bytes(&self, mime_type: &str) -> Option<Cow<'static, [u8]>> {
+        match mime_type {
+            "text/plain;charset=utf-8" | "text/plain" | "UTF8_STRING" => {
+                Some(Cow::Owned(self.0.as_bytes().to_vec()))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Text copied with [`ClickMode::HTML`], offered as HTML and as plain text.
+struct HtmlText {
+    html: String,
+    text: String,
+}
+
+impl AsMimeTypes for HtmlText {
+    fn available(&self) -> Cow<'static, [String]> {
+        Cow::Owned(vec![
+            "text/html".to_string(),
+            "text/plain;charset=utf-8".to_string(),
+            "text/plain".to_string(),
+            "UTF8_STRING".to_string(),
+        ])
+    }
+
+    fn as_bytes(&self, mime_type: &str) -> Option<Cow<'static, [u8]>> {
+        match mime_type {
+            "text/html" => Some(Cow::Owned(self.html.as_bytes().to_vec())),
+            "text/plain;charset=utf-8" | "text/plain" | "UTF8_STRING" => {
+                Some(Cow::Owned(self.text.as_bytes().to_vec()))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl From<flag::Flag> for Copyable {
+    fn from(flag: flag::Flag) -> Self {
+        match flag.emoji {
+            Some(emoji) => Copyable::Emoji(emoji),
+            // flags which are not RGI are never part of the history
+            None => Copyable::Text(flag.text),
+        }
+    }
 }
 #[derive(Clone, Debug)]
 pub enum Message {
@@ -53,7 +185,7 @@ pub enum Message {
     TogglePopup,
     PopupClosed(Id),
     Group(Option<emojis::Group>),
-    EmojiCopy(&'static emojis::Emoji, ClickMode),
+    EmojiCopy(Copyable, ClickMode),
     Search(String),
     Frame(std::time::Instant),
     EmojiHovered(&'static emojis::Emoji),
@@ -66,6 +198,70 @@ pub enum Message {
     _ScrollPixels(f32),
     Snap(f32),
     ToggleColorButton(usize),
+    ToggleGenderMode(GenderMode),
+    ToggleHairStyleMode(HairStyleMode),
+    Collection(usize),
+    CollectionName(String),
+    NewCollection(&'static emojis::Emoji),
+    CollectionIcon(usize, &'static emojis::Emoji),
+    RemoveCollection(usize),
+    ToggleInCollection(usize, &'static emojis::Emoji),
+    Page(Page),
+    RemoveFromHistory(&'static emojis::Emoji),
+    ClearHistory,
+    PauseHistory(bool),
+    SnippetHovered(usize),
+    OpenSnippets,
+    SnippetName(String),
+    SnippetText(String),
+    SaveSnippet,
+    RemoveSnippet(usize),
+    ExportStats,
+    ExportFavorites(ExportFormat),
+    Variants(&'static emojis::Emoji),
+    VariantPicked(&'static emojis::Emoji),
+    CloseVariants,
+    RememberSkinTone(bool),
+    OpenToneComposer(&'static emojis::Emoji),
+    ComposeSkinTone(usize, emojis::SkinTone),
+    ZwjBuilder(Vec<&'static emojis::Emoji>),
+    ZwjPush(&'static emojis::Emoji),
+    ZwjPop,
+    ZwjCopy,
+    CloseZwjBuilder,
+    FlagCode(String),
+    TransformText(String),
+    Transform(Transform),
+    AddColorButton,
+    RemoveColorButton(usize),
+    MoveColorButton(usize, usize),
+    EditColorButton(usize),
+    ColorButtonChannel(usize, usize, u8),
+    ColorButtonMode(usize, SkinToneMode),
+    SkinToneMode(SkinToneMode),
+    SkinToneFilter(SkinToneFilter),
+    ExpandVariants(&'static emojis::Emoji),
+    Typed(Result<(), String>),
+    Pasted(Result<(), String>),
+    PasteChord(String),
+    ClipboardSaved(Option<String>, String, Option<String>),
+    RestoreClipboard(String, String),
+    ClipboardChecked(Option<String>, String, String),
+    ComposeCursor(usize),
+    ComposeBackspace,
+    ComposeUndo,
+    ComposeCopy,
+    ComposeClear,
+    ImageSize(u32),
+    CopyImage(&'static emojis::Emoji),
+    ImageRendered(String, Result<Vec<u8>, String>),
+    SaveImage(&'static emojis::Emoji),
+    ImageSaved(Result<PathBuf, String>),
+    Presentation(Presentation),
+    RecordUse(&'static emojis::Emoji),
+    DragEmoji(&'static emojis::Emoji),
+    DragFinished,
+    DragCancelled,
 }
 
 #[derive(Clone, Debug)]
@@ -94,10 +290,34 @@ impl cosmic::Application for Window {
         flags: Self::Flags,
     ) -> (Self, Command<cosmic::app::Message<Self::Message>>) {
         let selected_group = None;
-        let config = flags.config;
+        let mut config = flags.config;
+        let usage_stats = match stats::data_dir() {
+            Some(data_dir) => match stats::load_or_migrate(&data_dir, &config.usage_stats) {
+                Ok(usage_stats) => {
+                    // older versions kept the usage in the config
+                    if !config.usage_stats.is_empty() {
+                        if let Some(config_handler) = &flags.config_handler {
+                            if let Err(err) = config.set_usage_stats(config_handler, Vec::new()) {
+                                eprintln!("failed to save config \"usage_stats\": {}", err);
+                            }
+                        }
+                    }
+                    usage_stats
+                }
+                Err(err) => {
+                    eprintln!("failed to load usage statistics {:?}: {}", data_dir, err);
+                    config.usage_stats.clone()
+                }
+            },
+            None => {
+                eprintln!("failed to load usage statistics: no data directory");
+                config.usage_stats.clone()
+            }
+        };
         let font_family =
             iced::Font::with_name(Box::leak(config.font_family.clone().into_boxed_str()));
 
+        let paste_chord = config.paste_chord.clone();
         let mut all_emojis = Vec::from_iter(utils::all_emojis(config.emoji_ordering));
 
         all_emojis.shrink_to_fit();
@@ -125,8 +345,39 @@ impl cosmic::Application for Window {
             search: String::new(),
             timeline: Timeline::new(),
             emoji_hovered: None,
+            snippet_hovered: None,
+            snippets_filtered: Vec::new(),
+            snippet_name: String::new(),
+            snippet_text: String::new(),
+            usage_stats,
+            stats_export: None,
+            image_export: None,
+            favorites_export: None,
+            dragged: None,
+            image_size_names: render::SIZES
+                .iter()
+                .map(|size| fl!("image-size", size = *size))
+                .collect(),
             text_input_id: widget::Id::unique(),
             annotations: annotations,
+            selected_collection: None,
+            page: Page::Emojis,
+            collection_name: String::new(),
+            paste_chord,
+            history_paused: false,
+            variants_popover: None,
+            composer_skin_tones: [emojis::SkinTone::Default; 2],
+            zwj_builder: None,
+            zwj_rejected: None,
+            flag_code: String::new(),
+            transform_text: String::new(),
+            transform: Transform::RegionalIndicators,
+            color_button_edited: None,
+            skin_tone_mode_names: color_button_editor::skin_tone_mode_names(),
+            expanded_emoji: None,
+            compose: Vec::new(),
+            compose_cursor: 0,
+            compose_undo: Vec::new(),
         };
 
         (
@@ -184,15 +435,114 @@ impl cosmic::Application for Window {
             }
             Message::ToggleColorButton(idx) => {
                 let mut color_buttons = self.config.color_buttons.clone();
+                let chosen = self.config.color_buttons_apply();
                 if let Some(color_button) = color_buttons.get_mut(idx) {
-                    color_button.active = !color_button.active;
+                    // buttons are shown inactive while they do not apply
+                    color_button.active = !(chosen && color_button.active);
                     let mut skin_tone_mode = self.config.skin_tone_mode;
-                    skin_tone_mode.set(color_button.skin_tone_mode, color_button.active);
-                    config_set!(skin_tone_mode, skin_tone_mode);
+                    // reserved bits of hand-edited color buttons are never set
+                    skin_tone_mode.set(
+                        color_button
+                            .skin_tone_mode
+                            .intersection(SkinToneMode::all()),
+                        color_button.active,
+                    );
                     config_set!(color_buttons, color_buttons);
-                    return cosmic::command::message(Message::Search(self.search.clone()));
+                    return cosmic::command::message(Message::SkinToneMode(skin_tone_mode));
                 }
             }
+            Message::AddColorButton => {
+                let mut color_buttons = self.config.color_buttons.clone();
+                color_buttons.push(ColorButton {
+                    color: rgba(SKIN_TONE_COLORS[0]),
+                    active: false,
+                    skin_tone_mode: SkinToneMode::DEFAULT,
+                });
+                self.color_button_edited = Some(color_buttons.len() - 1);
+                config_set!(color_buttons, color_buttons);
+            }
+            Message::RemoveColorButton(idx) => {
+                let mut color_buttons = self.config.color_buttons.clone();
+                if idx < color_buttons.len() {
+                    let removed = color_buttons.remove(idx);
+                    self.color_button_edited = None;
+                    config_set!(color_buttons, color_buttons);
+                    if removed.active {
+                        let mut skin_tone_mode = self.config.skin_tone_mode;
+                        skin_tone_mode.remove(removed.skin_tone_mode);
+                        return self.update_skin_tone_mode(skin_tone_mode);
+                    }
+                }
+            }
+            Message::MoveColorButton(from, to) => {
+                let mut color_buttons = self.config.color_buttons.clone();
+                if from < color_buttons.len() && to < color_buttons.len() {
+                    color_buttons.swap(from, to);
+                    if self.color_button_edited == Some(from) {
+                        self.color_button_edited = Some(to);
+                    } else if self.color_button_edited == Some(to) {
+                        self.color_button_edited = Some(from);
+                    }
+                    config_set!(color_buttons, color_buttons);
+                }
+            }
+            Message::EditColorButton(idx) => {
+                self.color_button_edited = (self.color_button_edited != Some(idx)).then_some(idx);
+            }
+            Message::ColorButtonChannel(idx, channel, value) => {
+                let mut color_buttons = self.config.color_buttons.clone();
+                if let Some(component) = color_buttons
+                    .get_mut(idx)
+                    .and_then(|color_button| color_button.color.get_mut(channel))
+                {
+                    *component = f32::from(value) / 255.;
+                    config_set!(color_buttons, color_buttons);
+                }
+            }
+            Message::ColorButtonMode(idx, mode) => {
+                if mode.has_reserved_bits() {
+                    eprintln!("skin tone mode {mode:?} sets reserved bits");
+                    return Command::none();
+                }
+                let mut color_buttons = self.config.color_buttons.clone();
+                if let Some(color_button) = color_buttons.get_mut(idx) {
+                    let previous_mode = std::mem::replace(&mut color_button.skin_tone_mode, mode);
+                    let active = color_button.active;
+                    config_set!(color_buttons, color_buttons);
+                    if active {
+                        let mut skin_tone_mode = self.config.skin_tone_mode;
+                        skin_tone_mode.remove(previous_mode);
+                        skin_tone_mode.insert(mode);
+                        return self.update_skin_tone_mode(skin_tone_mode);
+                    }
+                }
+            }
+            Message::SkinToneMode(skin_tone_mode) => {
+                // the color buttons choose the skin tones, but keep the matching of the menu
+                let skin_tone_filter = self
+                    .config
+                    .active_skin_tone_filter()
+                    .with_chosen_tones(skin_tone_mode);
+                config_set!(skin_tone_mode, skin_tone_mode);
+                config_set!(skin_tone_filter, Some(skin_tone_filter));
+                return cosmic::command::message(Message::Search(self.search.clone()));
+            }
+            Message::SkinToneFilter(skin_tone_filter) => {
+                config_set!(skin_tone_filter, Some(skin_tone_filter));
+                return cosmic::command::message(Message::Search(self.search.clone()));
+            }
+            Message::ToggleGenderMode(gender) => {
+                let mut gender_mode = self.config.gender_mode;
+                gender_mode.toggle(gender);
+                config_set!(gender_mode, gender_mode);
+                return cosmic::command::message(Message::Search(self.search.clone()));
+            }
+            Message::ToggleHairStyleMode(hair_style) => {
+                let mut hair_style_mode = self.config.hair_style_mode;
+                hair_style_mode.toggle(hair_style);
+                config_set!(hair_style_mode, hair_style_mode);
+                return cosmic::command::message(Message::Search(self.search.clone()));
+            }
             Message::Config(config) => {
                 if *config != self.config {
                     if config.font_family != self.config.font_family {
@@ -200,7 +550,24 @@ impl cosmic::Application for Window {
                             config.font_family.clone().into_boxed_str(),
                         ));
                     }
-                    self.config = *config
+                    if config.paste_chord != self.config.paste_chord {
+                        self.paste_chord = config.paste_chord.clone();
+                    }
+                    let collections_changed = config.collections != self.config.collections;
+                    // another applet instance may have changed the history
+                    let last_used_changed = config.last_used != self.config.last_used;
+                    self.config = *config;
+                    if collections_changed {
+                        if let Some(idx) = self.selected_collection {
+                            if idx >= self.config.collections.len() {
+                                return self.update_group(None);
+                            }
+                            return cosmic::command::message(Message::Search(self.search.clone()));
+                        }
+                    }
+                    if last_used_changed {
+                        return cosmic::command::message(Message::Search(self.search.clone()));
+                    }
                 }
             }
             Message::Frame(now) => self.timeline.now(now),
@@ -228,29 +595,118 @@ impl cosmic::Application for Window {
                     self.popup = None;
                 }
             }
-            Message::EmojiCopy(emoji, click_mode) => {
+            Message::EmojiCopy(copyable, mut click_mode) => {
                 let mut commands = Vec::new();
+                if self.history_paused {
+                    click_mode |= ClickMode::PRIVATE;
+                }
+                // the paste chord only reaches the focused application once the popup is closed
+                if click_mode.intersects(ClickMode::AUTO_PASTE) {
+                    click_mode |= ClickMode::COPY | ClickMode::CLOSE;
+                }
+                // while building a ZWJ sequence, picked emojis become its components
+                if let (Some(_), Copyable::Emoji(emoji)) = (&self.zwj_builder, &copyable) {
+                    if !click_mode.intersects(ClickMode::CONTEXT_MENU | ClickMode::VARIANTS) {
+                        return cosmic::command::message(Message::ZwjPush(*emoji));
+                    }
+                }
+                // snippets are already favorites and never part of the history
+                let emoji = match copyable {
+                    Copyable::Emoji(emoji) => Some(emoji),
+                    Copyable::Text(_) => None,
+                };
+                if let Some(emoji) =
+                    emoji.filter(|_| click_mode.intersects(ClickMode::CONTEXT_MENU))
+                {
+                    self.page = Page::ContextMenu(emoji);
+                }
+                if let Some(emoji) = emoji.filter(|_| click_mode.intersects(ClickMode::VARIANTS)) {
+                    if emoji.skin_tones().is_some() {
+                        self.variants_popover = Some(emoji);
+                    }
+                }
                 if click_mode.intersects(ClickMode::APPEND_SEARCH) {
-                    self.search.push_str(emoji.as_str());
+                    self.search.push_str(copyable.as_str());
                 }
-                if click_mode.intersects(ClickMode::COPY) {
-                    if !click_mode.intersects(ClickMode::PRIVATE) {
-                        let mut last_used = self.config.last_used.clone();
-                        if let Some(idx) = last_used.iter().position(|&e| e == emoji) {
-                            last_used.remove(idx);
-                        }
-                        last_used.push_front(emoji);
-                        last_used.truncate(self.config.last_used_limit);
-                        if let Some(idx) = self.favorites_filtered.iter().position(|&e| e == emoji)
-                        {
-                            self.favorites_filtered.remove(idx);
-                        }
-                        self.favorites_filtered.push_front(emoji);
-                        self.favorites_filtered
-                            .truncate(self.config.last_used_limit);
-                        config_set!(last_used, last_used);
+                if click_mode.intersects(ClickMode::COMPOSE) {
+                    self.compose_edit(|compose, cursor| {
+                        compose.insert(*cursor, copyable.as_str().to_string());
+                        *cursor += 1;
+                    });
+                }
+                let presentation =
+                    Presentation::from_click_mode(click_mode).unwrap_or(self.config.presentation);
+                let text = utils::with_presentation(copyable.as_str(), presentation);
+                let output = match CopyFormat::from_click_mode(click_mode) {
+                    Some(format) => format
+                        .format(
+                            &text,
+                            emoji.and_then(|emoji| emoji.shortcode()),
+                            emoji.map(|emoji| self.emoji_name_localized(emoji)),
+                        )
+                        .unwrap_or(text),
+                    None => text,
+                };
+                if click_mode.intersects(ClickMode::COPY | ClickMode::PRIMARY | ClickMode::TYPE) {
+                    if let Some(emoji) =
+                        emoji.filter(|_| !click_mode.intersects(ClickMode::PRIVATE))
+                    {
+                        commands.push(self.update(Message::RecordUse(emoji)));
+                    }
+                    let html = click_mode.intersects(ClickMode::HTML).then(|| {
+                        copy_format::html(
+                            &output,
+                            &self.config.font_family,
+                            emoji.map(|emoji| self.emoji_name_localized(emoji)),
+                        )
+                    });
+                    if click_mode.contains(ClickMode::COPY | ClickMode::RESTORE_CLIPBOARD) {
+                        let copied = output.clone();
+                        commands.push(iced::clipboard::read(move |saved| {
+                            cosmic::app::Message::App(Message::ClipboardSaved(
+                                saved,
+                                copied.clone(),
+                                html.clone(),
+                            ))
+                        }));
+                    } else if click_mode.intersects(ClickMode::COPY) {
+                        commands.push(clipboard_write(output.clone(), html))
+                    }
+                    if click_mode.intersects(ClickMode::PRIMARY) {
+                        commands.push(iced::clipboard::write_primary(output.clone()))
+                    }
+                    // typing already inserts the emoji
+                    if click_mode.intersects(ClickMode::AUTO_PASTE)
+                        && !click_mode.intersects(ClickMode::TYPE)
+                    {
+                        let chord = self.config.paste_chord.clone();
+                        commands.push(cosmic::command::future(async move {
+                            tokio::time::sleep(virtual_keyboard::DELAY).await;
+                            let pasted = tokio::task::spawn_blocking(move || {
+                                virtual_keyboard::press_chord(&chord)
+                                    .map_err(|err| format!("failed to press {:?}: {}", chord, err))
+                            })
+                            .await;
+                            Message::Pasted(pasted.unwrap_or_else(|err| Err(err.to_string())))
+                        }));
+                    }
+                    if click_mode.intersects(ClickMode::TYPE) {
+                        // the focused application only gets the keys once the popup is closed
+                        click_mode |= ClickMode::CLOSE;
+                        let text = output;
+                        let fallback = text.clone();
+                        commands.push(cosmic::command::future(async move {
+                            tokio::time::sleep(virtual_keyboard::DELAY).await;
+                            let typed = tokio::task::spawn_blocking(move || {
+                                virtual_keyboard::type_text(&text).map_err(|err| {
+                                    eprintln!("failed to type {:?}: {}", text, err);
+                                    text
+                                })
+                            })
+                            .await;
+                            Message::Typed(typed.unwrap_or(Err(fallback)))
+                        }));
                     }
-                    commands.push(iced::clipboard::write(emoji.to_string()))
                 }
 
                 if click_mode.intersects(ClickMode::CLOSE) {
@@ -262,6 +718,59 @@ impl cosmic::Application for Window {
                 }
                 return Command::batch(commands);
             }
+            Message::Presentation(presentation) => {
+                config_set!(presentation, presentation);
+            }
+            Message::RecordUse(emoji) => {
+                let mut last_used = stored(
+                    self.config_handler.as_ref(),
+                    "last_used",
+                    &self.config.last_used,
+                );
+                let history_diverged = last_used != self.config.last_used;
+                if let Some(idx) = last_used.iter().position(|&e| e == emoji) {
+                    last_used.remove(idx);
+                }
+                last_used.push_front(emoji);
+                last_used.truncate(self.config.last_used_limit);
+                if let Some(idx) = self.favorites_filtered.iter().position(|&e| e == emoji) {
+                    self.favorites_filtered.remove(idx);
+                }
+                self.favorites_filtered.push_front(emoji);
+                self.favorites_filtered
+                    .truncate(self.config.last_used_limit);
+                config_set!(last_used, last_used);
+                self.record_usage(emoji);
+                if history_diverged {
+                    return cosmic::command::message(Message::Search(self.search.clone()));
+                }
+            }
+            Message::DragEmoji(emoji) => {
+                self.dragged = Some(emoji);
+                self.variants_popover = None;
+                let icon: Element<'static, ()> = widget::text(emoji.as_str())
+                    .size(25)
+                    .font(self.font_family)
+                    .shaping(iced_core::text::Shaping::Advanced)
+                    .into();
+                return iced::clipboard::start_dnd(
+                    false,
+                    self.popup.map(DndSource::Surface),
+                    Some((icon, iced_core::widget::tree::State::None)),
+                    Box::new(DragText(utils::with_presentation(
+                        emoji.as_str(),
+                        self.config.presentation,
+                    ))),
+                    DndAction::Copy,
+                );
+            }
+            Message::DragFinished => {
+                // only drops which were accepted are part of the history
+                if let Some(emoji) = self.dragged.take().filter(|_| !self.history_paused) {
+                    return self.update(Message::RecordUse(emoji));
+                }
+            }
+            Message::DragCancelled => self.dragged = None,
             Message::Search(search) => {
                 let mut search_filtered = String::with_capacity(search.len());
                 self.all_emojis_aho_corasick.replace_all_with(
@@ -273,37 +782,67 @@ impl cosmic::Application for Window {
                 self.search = search;
 
                 self.emoji_hovered = None;
+                self.snippet_hovered = None;
+                self.variants_popover = None;
+                let search_lowercase = search_filtered.to_lowercase();
+                self.snippets_filtered = (0..self.config.snippets.len())
+                    .filter(|&idx| {
+                        search_lowercase.is_empty()
+                            || self.config.snippets[idx]
+                                .name
+                                .to_lowercase()
+                                .contains(&search_lowercase)
+                    })
+                    .collect();
                 self.emojis_filtered.clear();
                 self.favorites_filtered.clear();
-                let skin_tones_config = self.config.skin_tone_mode;
-                let skin_tones_exact = skin_tones_config.intersects(SkinToneMode::ALL_EXACT);
-                let skin_tones_intersect =
-                    skin_tones_config.intersects(SkinToneMode::FILTER_INTERSECT);
-                let skin_tones_mode_new = if skin_tones_exact {
-                    SkinToneMode::new_exact
-                } else {
-                    SkinToneMode::new
+                // flags which are not RGI are shown by `flag_search_result`
+                if let Some(code) = flag::search_code(&self.search) {
+                    self.snippets_filtered.clear();
+                    if let Some(emoji) = flag::compose(code).and_then(|flag| flag.emoji) {
+                        self.emojis_filtered.push(emoji);
+                    }
+                    return Command::none();
+                }
+                let skin_tone_filter = self.config.active_skin_tone_filter();
+                // collections are picked by hand, so they are not filtered by skin tone
+                let collection_emojis = self
+                    .selected_collection
+                    .and_then(|idx| self.config.collections.get(idx))
+                    .map(|collection| collection.emojis.clone());
+                let emojis_iter = match collection_emojis {
+                    Some(collection_emojis) => utils::Either::Left(collection_emojis.into_iter()),
+                    None => utils::Either::Right(utils::all_emojis_in_optional_group(
+                        self.config.emoji_ordering,
+                        self.selected_group,
+                    )),
                 };
-                for ref emoji in utils::all_emojis_in_optional_group(
-                    self.config.emoji_ordering,
-                    self.selected_group,
-                ) {
-                    let emjoji_skin_tone_mode = emoji
-                        .skin_tone()
-                        .map_or(SkinToneMode::NO_SKIN, skin_tones_mode_new);
+                let in_collection = self.selected_collection.is_some();
+                let gender_mode = self.config.gender_mode;
+                let hair_style_mode = self.config.hair_style_mode;
+                let skin_tone_accepted = |emoji: &'static emojis::Emoji| {
+                    skin_tone_filter.accepts(emoji.skin_tone())
+                        && gender_mode.contains(GenderMode::new(emoji))
+                        && hair_style_mode.contains(HairStyleMode::new(emoji))
+                };
+                for ref emoji in emojis_iter {
                     let config_skin_tone_contains_emoji =
-                        if skin_tones_exact || skin_tones_intersect {
-                            skin_tones_config.intersects(emjoji_skin_tone_mode)
-                        } else {
-                            skin_tones_config.contains(emjoji_skin_tone_mode)
-                        };
+                        in_collection || skin_tone_accepted(emoji);
+                    // the preferred skin tone takes the place of the default one,
+                    // unless it is shown anyway
+                    let emoji_shown = match self.config.preferred_skin_tones.get(emoji) {
+                        Some(&preferred) if !in_collection && !skin_tone_accepted(preferred) => {
+                            preferred
+                        }
+                        _ => *emoji,
+                    };
 
                     let emojis_in_conf = self.config.last_used.contains(emoji);
                     if search_filtered.is_empty()
                         || self.emoji_name_localized(emoji).contains(&search_filtered)
                     {
                         if config_skin_tone_contains_emoji {
-                            self.emojis_filtered.push(emoji);
+                            self.emojis_filtered.push(emoji_shown);
                         }
                         if emojis_in_conf {
                             self.favorites_filtered.push_back(emoji);
@@ -312,9 +851,352 @@ impl cosmic::Application for Window {
                 }
             }
             Message::Group(group) => return self.update_group(group),
+            Message::Collection(idx) => return self.update_collection(idx),
+            Message::CollectionName(name) => self.collection_name = name,
+            Message::NewCollection(emoji) => {
+                let name = self.collection_name.trim();
+                if name.is_empty() || self.config.collections.len() >= MAX_COLLECTIONS {
+                    return Command::none();
+                }
+                let mut collections = self.config.collections.clone();
+                collections.push(Collection {
+                    name: name.to_string(),
+                    icon: emoji,
+                    emojis: vec![emoji],
+                });
+                self.collection_name.clear();
+                config_set!(collections, collections);
+            }
+            Message::CollectionIcon(idx, emoji) => {
+                let mut collections = self.config.collections.clone();
+                if let Some(collection) = collections.get_mut(idx) {
+                    collection.icon = emoji;
+                    config_set!(collections, collections);
+                }
+            }
+            Message::RemoveCollection(idx) => {
+                let mut collections = self.config.collections.clone();
+                if idx < collections.len() {
+                    collections.remove(idx);
+                    config_set!(collections, collections);
+                    if self.selected_collection.is_some() {
+                        return self.update_group(None);
+                    }
+                }
+            }
+            Message::ToggleInCollection(idx, emoji) => {
+                let mut collections = self.config.collections.clone();
+                if let Some(collection) = collections.get_mut(idx) {
+                    match collection.emojis.iter().position(|&e| e == emoji) {
+                        Some(pos) => {
+                            collection.emojis.remove(pos);
+                        }
+                        None => collection.emojis.push(emoji),
+                    }
+                    config_set!(collections, collections);
+                    if self.selected_collection == Some(idx) {
+                        return cosmic::command::message(Message::Search(self.search.clone()));
+                    }
+                }
+            }
+            Message::Page(page) => {
+                if matches!(page, Page::Stats) {
+                    self.reload_usage();
+                }
+                self.stats_export = None;
+                self.image_export = None;
+                self.favorites_export = None;
+                self.page = page;
+            }
+            Message::Variants(emoji) => self.variants_popover = Some(emoji),
+            Message::ClipboardSaved(saved, copied, html) => {
+                let write = clipboard_write(copied.clone(), html);
+                let Some(saved) = saved.filter(|saved| *saved != copied) else {
+                    return write;
+                };
+                let timeout = Duration::from_millis(self.config.restore_clipboard_timeout);
+                let restore = cosmic::command::future(async move {
+                    tokio::time::sleep(timeout).await;
+                    Message::RestoreClipboard(saved, copied)
+                });
+                return Command::batch([write, restore]);
+            }
+            Message::RestoreClipboard(saved, copied) => {
+                return iced::clipboard::read(move |current| {
+                    cosmic::app::Message::App(Message::ClipboardChecked(
+                        current,
+                        saved.clone(),
+                        copied.clone(),
+                    ))
+                });
+            }
+            Message::ClipboardChecked(current, saved, copied) => {
+                // something else was copied in the meantime, which is kept,
+                // including images and files, which are not read as text
+                if current == Some(copied) {
+                    return iced::clipboard::write(saved);
+                }
+            }
+            Message::ComposeCursor(cursor) => {
+                self.compose_cursor = cursor.min(self.compose.len());
+            }
+            Message::ComposeBackspace => self.compose_edit(|compose, cursor| {
+                if *cursor > 0 {
+                    *cursor -= 1;
+                    compose.remove(*cursor);
+                }
+            }),
+            Message::ComposeUndo => {
+                if let Some((compose, cursor)) = self.compose_undo.pop() {
+                    self.compose = compose;
+                    self.compose_cursor = cursor;
+                }
+            }
+            Message::ComposeClear => {
+                self.compose.clear();
+                self.compose_cursor = 0;
+                self.compose_undo.clear();
+            }
+            Message::ComposeCopy => {
+                let composed = self.compose.concat();
+                self.compose.clear();
+                self.compose_cursor = 0;
+                self.compose_undo.clear();
+                let click_mode = self.pick_click_mode().difference(ClickMode::COMPOSE);
+                return cosmic::command::message(Message::EmojiCopy(
+                    Copyable::Text(composed),
+                    click_mode,
+                ));
+            }
+            Message::Typed(typed) => {
+                // without a virtual keyboard the text is copied instead
+                if let Err(text) = typed {
+                    return iced::clipboard::write(text);
+                }
+            }
+            Message::Pasted(pasted) => {
+                if let Err(err) = pasted {
+                    eprintln!("{}", err);
+                }
+            }
+            Message::PasteChord(paste_chord) => {
+                self.paste_chord = paste_chord.clone();
+                if virtual_keyboard::parse_chord(&paste_chord).is_ok() {
+                    config_set!(paste_chord, paste_chord);
+                }
+            }
+            Message::ExpandVariants(emoji) => {
+                self.expanded_emoji = (self.expanded_emoji != Some(emoji)).then_some(emoji);
+            }
+            Message::CloseVariants => self.variants_popover = None,
+            Message::RememberSkinTone(remember) => config_set!(remember_skin_tone, remember),
+            Message::VariantPicked(variant) => {
+                self.variants_popover = None;
+                if self.config.remember_skin_tone {
+                    if let Some(base) = variant.skin_tones().and_then(|mut tones| tones.next()) {
+                        let mut preferred_skin_tones = self.config.preferred_skin_tones.clone();
+                        if base == variant {
+                            preferred_skin_tones.remove(base);
+                        } else {
+                            preferred_skin_tones.insert(base, variant);
+                        }
+                        config_set!(preferred_skin_tones, preferred_skin_tones);
+                    }
+                }
+                return Command::batch([
+                    cosmic::command::message(Message::EmojiCopy(
+                        Copyable::Emoji(variant),
+                        self.pick_click_mode(),
+                    )),
+                    cosmic::command::message(Message::Search(self.search.clone())),
+                ]);
+            }
+            Message::ZwjBuilder(components) => {
+                self.page = Page::Emojis;
+                self.zwj_builder = Some(components);
+                self.zwj_rejected = None;
+            }
+            Message::ZwjPush(emoji) => {
+                if let Some(components) = &mut self.zwj_builder {
+                    if utils::zwj_extends(components, emoji) {
+                        components.push(emoji);
+                        self.zwj_rejected = None;
+                    } else {
+                        self.zwj_rejected = Some(emoji);
+                    }
+                }
+            }
+            Message::ZwjPop => {
+                if let Some(components) = &mut self.zwj_builder {
+                    components.pop();
+                }
+                self.zwj_rejected = None;
+            }
+            Message::ZwjCopy => {
+                let zwj_emoji = self.zwj_builder.as_deref().and_then(utils::zwj_emoji);
+                if let Some(zwj_emoji) = zwj_emoji {
+                    self.zwj_builder = None;
+                    self.zwj_rejected = None;
+                    return cosmic::command::message(Message::EmojiCopy(
+                        Copyable::Emoji(zwj_emoji),
+                        self.pick_click_mode(),
+                    ));
+                }
+            }
+            Message::FlagCode(flag_code) => self.flag_code = flag_code,
+            Message::TransformText(transform_text) => self.transform_text = transform_text,
+            Message::Transform(transform) => self.transform = transform,
+            Message::CloseZwjBuilder => {
+                self.zwj_builder = None;
+                self.zwj_rejected = None;
+            }
+            Message::OpenToneComposer(emoji) => {
+                self.variants_popover = None;
+                self.composer_skin_tones = match emoji.skin_tone() {
+                    Some(skin_tone) => {
+                        let (first, second) = utils::split_skin_tone(skin_tone);
+                        [first, second]
+                    }
+                    None => [emojis::SkinTone::Default; 2],
+                };
+                self.page = Page::ToneComposer(emoji);
+            }
+            Message::ComposeSkinTone(person, skin_tone) => {
+                if let Some(composer_skin_tone) = self.composer_skin_tones.get_mut(person) {
+                    *composer_skin_tone = skin_tone;
+                }
+            }
+            Message::ExportStats => {
+                let Some(data_dir) = stats::data_dir() else {
+                    self.stats_export = Some(Err(fl!("no-data-dir")));
+                    return Command::none();
+                };
+                self.stats_export = Some(match stats::export_csv(&data_dir, &self.usage_stats) {
+                    Ok(path) => Ok(path),
+                    Err(err) => {
+                        eprintln!("failed to export usage statistics {:?}: {}", data_dir, err);
+                        Err(err.to_string())
+                    }
+                });
+            }
+            Message::ImageSize(image_size) => {
+                config_set!(image_size, image_size);
+            }
+            Message::CopyImage(emoji) => {
+                let font_family = self.config.font_family.clone();
+                let size = self.image_size();
+                return cosmic::command::future(async move {
+                    let png = tokio::task::spawn_blocking(move || {
+                        render::render_png(emoji.as_str(), &font_family, size)
+                            .map_err(|err| err.to_string())
+                    })
+                    .await
+                    .unwrap_or_else(|err| Err(err.to_string()));
+                    Message::ImageRendered(emoji.to_string(), png)
+                });
+            }
+            Message::ImageRendered(text, png) => match png {
+                Ok(png) => return iced::clipboard::write_data(render::EmojiImage { png, text }),
+                Err(err) => {
+                    eprintln!("failed to render {:?} as an image: {}", text, err);
+                    self.image_export = Some(Err(err));
+                }
+            },
+            Message::SaveImage(emoji) => {
+                let font_family = self.config.font_family.clone();
+                let size = self.image_size();
+                let stem = format!("{}-{}", utils::file_stem(emoji), size);
+                let home = PathBuf::from(std::env::var_os("HOME").unwrap_or_default());
+                return cosmic::command::future(async move {
+                    let saved = tokio::task::spawn_blocking(move || {
+                        let png = render::render_png(emoji.as_str(), &font_family, size)
+                            .map_err(|err| err.to_string())?;
+                        utils::create_new_file(&home, &stem, "png", &png)
+                            .map_err(|err| err.to_string())
+                    })
+                    .await
+                    .unwrap_or_else(|err| Err(err.to_string()));
+                    Message::ImageSaved(saved)
+                });
+            }
+            Message::ImageSaved(saved) => {
+                if let Err(err) = &saved {
+                    eprintln!("failed to save image: {}", err);
+                }
+                self.image_export = Some(saved);
+            }
+            Message::ExportFavorites(format) => {
+                let home = std::env::var_os("HOME").unwrap_or_default();
+                let path = PathBuf::from(home).join(format.file_name());
+                self.favorites_export =
+                    Some(match std::fs::write(&path, format.export(&self.config)) {
+                        Ok(()) => Ok(path),
+                        Err(err) => {
+                            eprintln!("failed to export favorites {:?}: {}", path, err);
+                            Err(err.to_string())
+                        }
+                    });
+            }
+            Message::RemoveFromHistory(emoji) => {
+                let mut last_used = stored(
+                    self.config_handler.as_ref(),
+                    "last_used",
+                    &self.config.last_used,
+                );
+                if let Some(idx) = last_used.iter().position(|&e| e == emoji) {
+                    last_used.remove(idx);
+                    if let Some(idx) = self.favorites_filtered.iter().position(|&e| e == emoji) {
+                        self.favorites_filtered.remove(idx);
+                    }
+                    config_set!(last_used, last_used);
+                }
+            }
+            Message::ClearHistory => {
+                self.favorites_filtered.clear();
+                config_set!(last_used, VecDeque::new());
+            }
+            Message::PauseHistory(paused) => self.history_paused = paused,
 
-            Message::EmojiHovered(emoji) => self.emoji_hovered = Some(emoji),
+            Message::EmojiHovered(emoji) => {
+                self.emoji_hovered = Some(emoji);
+                self.snippet_hovered = None;
+            }
+            Message::SnippetHovered(idx) => {
+                self.snippet_hovered = Some(idx);
+                self.emoji_hovered = None;
+            }
+            Message::OpenSnippets => {
+                self.snippet_text = self.search.clone();
+                self.page = Page::Snippets;
+            }
+            Message::SnippetName(name) => self.snippet_name = name,
+            Message::SnippetText(text) => self.snippet_text = text,
+            Message::SaveSnippet => {
+                let name = self.snippet_name.trim();
+                if name.is_empty() || self.snippet_text.is_empty() {
+                    return Command::none();
+                }
+                let mut snippets = self.config.snippets.clone();
+                snippets.push(Snippet {
+                    name: name.to_string(),
+                    text: std::mem::take(&mut self.snippet_text),
+                });
+                self.snippet_name.clear();
+                config_set!(snippets, snippets);
+                return cosmic::command::message(Message::Search(self.search.clone()));
+            }
+            Message::RemoveSnippet(idx) => {
+                let mut snippets = self.config.snippets.clone();
+                if idx < snippets.len() {
+                    snippets.remove(idx);
+                    config_set!(snippets, snippets);
+                    return cosmic::command::message(Message::Search(self.search.clone()));
+                }
+            }
             Message::Exit => {
+                self.page = Page::Emojis;
+                self.zwj_builder = None;
+                self.zwj_rejected = None;
                 self.emojis_filtered.shrink_to_fit();
                 self.favorites_filtered.shrink_to_fit();
                 if let Some(p) = self.popup.take() {
@@ -322,10 +1204,21 @@ impl cosmic::Application for Window {
                 }
             }
             Message::Enter => {
-                let emoji_opt = self.emoji_selected();
-                if let Some(emoji) = emoji_opt {
+                let copyable_opt = match self.snippet_hovered {
+                    Some(idx) => self
+                        .config
+                        .snippets
+                        .get(idx)
+                        .map(|snippet| Copyable::Text(snippet.text.clone())),
+                    None => self.emoji_selected().map(Copyable::Emoji).or_else(|| {
+                        flag::search_code(&self.search)
+                            .and_then(flag::compose)
+                            .map(Copyable::from)
+                    }),
+                };
+                if let Some(copyable) = copyable_opt {
                     return cosmic::command::message(Message::EmojiCopy(
-                        emoji,
+                        copyable,
                         ClickMode::COPY | ClickMode::CLOSE,
                     ));
                 }
@@ -351,12 +1244,56 @@ impl cosmic::Application for Window {
             .padding([8, 8])
             .spacing(cosmic::theme::active().cosmic().space_xxs());
 
+        match self.page {
+            Page::Emojis => {}
+            Page::ContextMenu(emoji) => {
+                content = content.push(self.context_menu(emoji));
+                return self.core.applet.popup_container(content).into();
+            }
+            Page::Menu => {
+                content = content.push(self.menu());
+                return self.core.applet.popup_container(content).into();
+            }
+            Page::Snippets => {
+                content = content.push(self.snippets());
+                return self.core.applet.popup_container(content).into();
+            }
+            Page::Stats => {
+                content = content.push(self.usage());
+                return self.core.applet.popup_container(content).into();
+            }
+            Page::ToneComposer(emoji) => {
+                content = content.push(self.tone_composer(emoji));
+                return self.core.applet.popup_container(content).into();
+            }
+            Page::FlagComposer => {
+                content = content.push(self.flag_composer());
+                return self.core.applet.popup_container(content).into();
+            }
+            Page::ColorButtons => {
+                content = content.push(self.color_button_editor());
+                return self.core.applet.popup_container(content).into();
+            }
+            Page::TextTransform => {
+                content = content.push(self.text_transform());
+                return self.core.applet.popup_container(content).into();
+            }
+        }
+
         let groups = self.group_icons();
         content = content.push(groups);
 
         let search = self.search();
         content = content.push(search);
 
+        if !self.compose.is_empty() || !self.compose_undo.is_empty() {
+            content = content.push(self.compose_bar());
+        }
+
+        if let Some(components) = &self.zwj_builder {
+            content = content.push(self.zwj_builder_bar(components));
+        }
+
         let preview = self.preview();
         content = content.push(preview);
 
@@ -387,7 +1324,17 @@ impl cosmic::Application for Window {
             .as_subscription()
             .map(|(_, now)| Message::Frame(now));
 
-        Subscription::batch(vec![config, timeline, nav::subscription()])
+        let dnd = cosmic::iced_futures::event::listen_with(|event, _| match event {
+            iced::Event::Dnd(DndEvent::Source(SourceEvent::Finished)) => {
+                Some(Message::DragFinished)
+            }
+            iced::Event::Dnd(DndEvent::Source(SourceEvent::Cancelled)) => {
+                Some(Message::DragCancelled)
+            }
+            _ => None,
+        });
+
+        Subscription::batch(vec![config, timeline, nav::subscription(), dnd])
     }
 
     fn style(&self) -> Option<<Theme as application::StyleSheet>::Style> {
@@ -395,7 +1342,18 @@ impl cosmic::Application for Window {
     }
 }
 
+mod color_button_editor;
+mod compose;
+mod context_menu;
+mod flag_composer;
+mod menu;
+mod snippets;
 mod style;
+mod text_transform;
+mod tone_composer;
+mod usage;
+mod variants;
+mod zwj_builder;
 
 impl Window {
     fn emoji_name_localized(&self, emoji: &'static emojis::Emoji) -> &str {
@@ -407,12 +1365,64 @@ impl Window {
         emoji_name
     }
 
+    /// Updates the skin tones of edited color buttons,
+    /// without choosing them as the filter if another one is selected in the menu.
+    fn update_skin_tone_mode(
+        &mut self,
+        skin_tone_mode: SkinToneMode,
+    ) -> Command<cosmic::app::Message<Message>> {
+        if self.config.color_buttons_apply() {
+            return cosmic::command::message(Message::SkinToneMode(skin_tone_mode));
+        }
+        match &self.config_handler {
+            Some(config_handler) => {
+                if let Err(err) = self
+                    .config
+                    .set_skin_tone_mode(config_handler, skin_tone_mode)
+                {
+                    eprintln!("failed to save config \"skin_tone_mode\": {}", err);
+                }
+            }
+            None => {
+                self.config.skin_tone_mode = skin_tone_mode;
+                eprintln!("failed to save config \"skin_tone_mode\": no config handler");
+            }
+        }
+        Command::none()
+    }
+
+    /// Reads the usage of other applet instances from disk.
+    fn reload_usage(&mut self) {
+        let Some(data_dir) = stats::data_dir() else {
+            return;
+        };
+        match stats::load(&data_dir) {
+            Ok(usage_stats) => self.usage_stats = usage_stats.unwrap_or_default(),
+            Err(err) => eprintln!("failed to load usage statistics {:?}: {}", data_dir, err),
+        }
+    }
+
+    /// Counts one use of `emoji`, merged with the usage of other applet instances.
+    fn record_usage(&mut self, emoji: &'static emojis::Emoji) {
+        self.reload_usage();
+        stats::record(&mut self.usage_stats, emoji, stats::today());
+        let Some(data_dir) = stats::data_dir() else {
+            eprintln!("failed to save usage statistics: no data directory");
+            return;
+        };
+        if let Err(err) = stats::save(&data_dir, &self.usage_stats) {
+            eprintln!("failed to save usage statistics {:?}: {}", data_dir, err);
+        }
+    }
+
     fn update_group(
         &mut self,
         group: Option<emojis::Group>,
     ) -> Command<cosmic::app::Message<Message>> {
         self.emoji_hovered = None;
+        self.snippet_hovered = None;
         self.selected_group = group;
+        self.selected_collection = None;
         return Command::batch([
             scrollable::scroll_to(
                 self.scrollable_id.clone(),
@@ -422,44 +1432,65 @@ impl Window {
         ]);
     }
 
+    /// The configured image size, limited to the offered sizes.
+    fn image_size(&self) -> u32 {
+        let sizes = render::SIZES;
+        self.config
+            .image_size
+            .clamp(sizes[0], sizes[sizes.len() - 1])
+    }
+
+    /// Edits the compose buffer, which can be undone.
+    fn compose_edit(&mut self, edit: impl FnOnce(&mut Vec<String>, &mut usize)) {
+        let previous = (self.compose.clone(), self.compose_cursor);
+        edit(&mut self.compose, &mut self.compose_cursor);
+        if previous.0 != self.compose {
+            self.compose_undo.push(previous);
+        }
+    }
+
+    /// The click mode for picking an emoji from a popover or page, which acts
+    /// like a left click, but must not open the popover or context menu again.
+    fn pick_click_mode(&self) -> ClickMode {
+        let click_mode = self
+            .config
+            .left_click_action
+            .difference(ClickMode::VARIANTS | ClickMode::CONTEXT_MENU);
+        if click_mode.intersects(ClickMode::COPY | ClickMode::PRIMARY | ClickMode::TYPE) {
+            click_mode
+        } else {
+            click_mode | ClickMode::COPY
+        }
+    }
+
+    fn update_collection(&mut self, idx: usize) -> Command<cosmic::app::Message<Message>> {
+        if idx >= self.config.collections.len() {
+            return Command::none();
+        }
+        let command = self.update_group(None);
+        self.selected_collection = Some(idx);
+        command
+    }
+
     fn emojis_flex(
         &self,
         emojis_list: impl IntoIterator<Item = &'static emojis::Emoji>,
+        variants_popover: Option<&'static emojis::Emoji>,
+        grouped: bool,
     ) -> widget::FlexRow<'static, Message> {
         let emojis_list = emojis_list.into_iter();
         let mut emojis_view = Vec::with_capacity(emojis_list.size_hint().0);
 
-        let left_click_action = self.config.left_click_action;
-        let right_click_action = self.config.right_click_action;
-        let middle_click_action = self.config.middle_click_action;
         for emoji in emojis_list {
-            let emoji_txt = widget::text(emoji.as_str())
-                .size(25)
-                .width(35)
-                .height(35)
-                .font(self.font_family)
-                .shaping(iced_core::text::Shaping::Advanced)
-                .wrap(iced::widget::text::Wrap::None)
-                .horizontal_alignment(alignment::Horizontal::Center)
-                .vertical_alignment(alignment::Vertical::Center);
-
-            let mut emoji_btn: widget::Button<'_, Message> =
-                widget::button(emoji_txt).style(cosmic::theme::Button::Transparent);
-            if left_click_action != ClickMode::NONE {
-                emoji_btn = emoji_btn.on_press(Message::EmojiCopy(emoji, left_click_action));
-            }
-            let mut emoji_mouse_area =
-                widget_copy::MouseArea::new(emoji_btn).on_enter(Message::EmojiHovered(emoji));
-
-            if right_click_action != ClickMode::NONE {
-                emoji_mouse_area = emoji_mouse_area
-                    .on_right_release(Message::EmojiCopy(emoji, right_click_action));
-            }
-            if middle_click_action != ClickMode::NONE {
-                emoji_mouse_area = emoji_mouse_area
-                    .on_middle_release(Message::EmojiCopy(emoji, middle_click_action));
+            let variants = emoji.skin_tones().filter(|_| grouped);
+            emojis_view.push(self.emoji_tile(emoji, variants_popover, variants.is_some()));
+            // the variants of an expanded tile follow it inline, starting with the
+            // default skin tone, since clicking the tile itself only expands it
+            if self.expanded_emoji == Some(emoji) {
+                for variant in variants.into_iter().flatten() {
+                    emojis_view.push(self.emoji_tile(variant, variants_popover, false));
+                }
             }
-            emojis_view.push(emoji_mouse_area.into());
         }
 
         let flex_row = widget::flex_row(emojis_view)
@@ -471,33 +1502,110 @@ impl Window {
         flex_row
     }
 
-    fn search(&self) -> widget::TextInput<'_, Message> {
+    /// A tile of the emoji grid, expanding its skin tones when they are `grouped`.
+    fn emoji_tile(
+        &self,
+        emoji: &'static emojis::Emoji,
+        variants_popover: Option<&'static emojis::Emoji>,
+        grouped: bool,
+    ) -> Element<'static, Message> {
+        let left_click_action = self.config.left_click_action;
+        let right_click_action = self.config.right_click_action;
+        let middle_click_action = self.config.middle_click_action;
+
+        let emoji_txt = widget::text(emoji.as_str())
+            .size(25)
+            .width(if grouped { 29 } else { 35 })
+            .height(35)
+            .font(self.font_family)
+            .shaping(iced_core::text::Shaping::Advanced)
+            .wrap(iced::widget::text::Wrap::None)
+            .horizontal_alignment(alignment::Horizontal::Center)
+            .vertical_alignment(alignment::Vertical::Center);
+        let emoji_content: Element<'static, Message> = if grouped {
+            let indicator = if self.expanded_emoji == Some(emoji) {
+                "\u{25e4}"
+            } else {
+                "\u{25e2}"
+            };
+            widget::row::with_capacity(2)
+                .push(emoji_txt)
+                .push(
+                    widget::text(indicator)
+                        .size(8)
+                        .width(6)
+                        .height(35)
+                        .vertical_alignment(alignment::Vertical::Bottom),
+                )
+                .into()
+        } else {
+            emoji_txt.into()
+        };
+
+        let mut emoji_btn: widget::Button<'_, Message> =
+            widget::button(emoji_content).style(cosmic::theme::Button::Transparent);
+        if grouped {
+            emoji_btn = emoji_btn.on_press(Message::ExpandVariants(emoji));
+        } else if left_click_action != ClickMode::NONE {
+            emoji_btn = emoji_btn.on_press(Message::EmojiCopy(
+                Copyable::Emoji(emoji),
+                left_click_action,
+            ));
+        }
+        let mut emoji_mouse_area = widget_copy::MouseArea::new(emoji_btn)
+            .on_enter(Message::EmojiHovered(emoji))
+            .on_drag(Message::DragEmoji(emoji));
+        // emojis without skin tones open the context menu instead
+        let long_press = match emoji.skin_tones() {
+            Some(_) => Message::Variants(emoji),
+            None => Message::Page(Page::ContextMenu(emoji)),
+        };
+        emoji_mouse_area = emoji_mouse_area.on_long_press(long_press);
+
+        if right_click_action != ClickMode::NONE {
+            emoji_mouse_area = emoji_mouse_area.on_right_release(Message::EmojiCopy(
+                Copyable::Emoji(emoji),
+                right_click_action,
+            ));
+        }
+        if middle_click_action != ClickMode::NONE {
+            emoji_mouse_area = emoji_mouse_area.on_middle_release(Message::EmojiCopy(
+                Copyable::Emoji(emoji),
+                middle_click_action,
+            ));
+        }
+        if variants_popover == Some(emoji) {
+            let popover = widget::popover(emoji_mouse_area)
+                .popup(self.variants_popup(emoji))
+                .on_close(Message::CloseVariants);
+            popover.into()
+        } else {
+            emoji_mouse_area.into()
+        }
+    }
+
+    fn search(&self) -> widget::Row<'_, Message> {
         let search = widget::search_input(fl!("search-for-emojis"), &self.search)
             .on_clear(Message::Search(String::new()))
             .id(self.text_input_id.clone())
             .on_paste(Message::Search)
             .on_input(Message::Search)
             .on_submit(Message::Enter);
-        search
+        let menu_btn =
+            widget::button::icon(widget::icon::from_name("open-menu-symbolic").symbolic(true))
+                .on_press(Message::Page(Page::Menu));
+        widget::row::with_capacity(2)
+            .spacing(cosmic::theme::active().cosmic().space_xxs())
+            .align_items(Alignment::Center)
+            .push(search)
+            .push(menu_btn)
     }
 
     fn group_icons(&self) -> widget::Row<'static, Message> {
         let mut groups = widget::row::with_capacity(9).width(Length::Fill);
-        use crate::style_copy::button;
         for group in emojis::Group::iter() {
             let is_selected = self.selected_group.is_some_and(|sel| sel == group);
-            let buton_style = cosmic::theme::Button::Custom {
-                active: Box::new(move |focused, theme| {
-                    button::active(theme, focused, is_selected, button::Button::Icon)
-                }),
-                pressed: Box::new(move |focused, theme| {
-                    button::pressed(theme, focused, is_selected, button::Button::Icon)
-                }),
-                hovered: Box::new(move |focused, theme| {
-                    button::hovered(theme, focused, is_selected, button::Button::Icon)
-                }),
-                disabled: Box::new(|_theme| widget::button::Appearance::new()),
-            };
+            let buton_style = group_button_style(is_selected);
             let group_btn =
                 widget::button::icon(widget::icon::from_name(group_icon(group)).symbolic(true))
                     .font_size(20)
@@ -514,27 +1622,38 @@ impl Window {
 
             groups = groups.push(group_btn);
         }
+        for (idx, collection) in self.config.collections.iter().enumerate() {
+            let is_selected = self.selected_collection == Some(idx);
+            let icon_txt = widget::text(collection.icon.as_str())
+                .size(18)
+                .font(self.font_family)
+                .shaping(iced_core::text::Shaping::Advanced)
+                .horizontal_alignment(alignment::Horizontal::Center)
+                .vertical_alignment(alignment::Vertical::Center);
+            let collection_btn = widget::button(icon_txt)
+                .padding(cosmic::theme::active().cosmic().space_xxs())
+                .style(group_button_style(is_selected))
+                .on_press(if is_selected {
+                    Message::Group(None)
+                } else {
+                    Message::Collection(idx)
+                })
+                .apply(widget::container)
+                .width(Length::Fill)
+                .center_y()
+                .center_x();
+            groups = groups.push(collection_btn);
+        }
         groups
     }
 
     fn color_buttons(&self) -> widget::Row<'static, Message> {
         let color_buttons_conf = &self.config.color_buttons;
+        let chosen = self.config.color_buttons_apply();
         let mut color_buttons = widget::row::with_capacity(color_buttons_conf.len());
         for (idx, color_button) in color_buttons_conf.iter().enumerate() {
-            let color = color_button.color;
-            let active = color_button.active;
-            let button_style = cosmic::theme::Button::Custom {
-                active: Box::new(move |_selected, theme| {
-                    style::color_button_apperance(color, Some(active), theme)
-                }),
-                disabled: Box::new(move |theme| style::color_button_apperance(color, None, theme)),
-                hovered: Box::new(move |_selected, theme| {
-                    style::color_button_apperance(color, Some(active), theme)
-                }),
-                pressed: Box::new(move |_selected, theme| {
-                    style::color_button_apperance(color, Some(active), theme)
-                }),
-            };
+            let button_style =
+                style::color_button_style(color_button.color, chosen && color_button.active);
 
             color_buttons = color_buttons.push(
                 widget::button(widget::horizontal_space(0.1))
@@ -547,6 +1666,59 @@ impl Window {
         color_buttons
     }
 
+    fn variant_filter_buttons(&self) -> widget::Row<'static, Message> {
+        let gender_mode = self.config.gender_mode;
+        let hair_style_mode = self.config.hair_style_mode;
+        let genders = [
+            ("\u{1f9d1}", GenderMode::PERSON),
+            ("\u{1f468}", GenderMode::MAN),
+            ("\u{1f469}", GenderMode::WOMAN),
+        ]
+        .map(|(icon, gender)| {
+            self.variant_filter_button(
+                icon,
+                gender_mode.contains(gender),
+                Message::ToggleGenderMode(gender),
+            )
+        });
+        let hair_styles = [
+            ("\u{1f9b0}", HairStyleMode::RED),
+            ("\u{1f9b1}", HairStyleMode::CURLY),
+            ("\u{1f9b3}", HairStyleMode::WHITE),
+            ("\u{1f9b2}", HairStyleMode::BALD),
+        ]
+        .map(|(icon, hair_style)| {
+            self.variant_filter_button(
+                icon,
+                hair_style_mode.contains(hair_style),
+                Message::ToggleHairStyleMode(hair_style),
+            )
+        });
+        widget::row::with_children(genders.into_iter().chain(hair_styles).collect())
+    }
+
+    fn variant_filter_button(
+        &self,
+        icon: &'static str,
+        active: bool,
+        message: Message,
+    ) -> Element<'static, Message> {
+        let icon_txt = widget::text(icon)
+            .size(14)
+            .font(self.font_family)
+            .shaping(iced_core::text::Shaping::Advanced)
+            .wrap(iced::widget::text::Wrap::None)
+            .horizontal_alignment(alignment::Horizontal::Center)
+            .vertical_alignment(alignment::Vertical::Center);
+        widget::button(icon_txt)
+            .width(20)
+            .height(20)
+            .padding(0)
+            .style(group_button_style(active))
+            .on_press(message)
+            .into()
+    }
+
     fn emoji_selected(&self) -> Option<&'static emojis::Emoji> {
         let emoji_opt = self
             .emoji_hovered
@@ -557,14 +1729,40 @@ impl Window {
 
     fn emojis_section(&self) -> widget::Container<'static, Message, Theme> {
         let mut emojis_section =
-            widget::column::with_capacity(3).spacing(cosmic::theme::active().cosmic().space_xxs());
+            widget::column::with_capacity(4).spacing(cosmic::theme::active().cosmic().space_xxs());
+        if !self.snippets_filtered.is_empty() {
+            emojis_section = emojis_section.push(self.snippets_flex());
+        }
+        if let Some(flag_search_result) =
+            flag::search_code(&self.search).and_then(|code| self.flag_search_result(code))
+        {
+            emojis_section = emojis_section.push(flag_search_result);
+        }
+        // the popover is only shown once, even if the emoji is also a favorite
+        let (favorites_popover, emojis_popover) = match self.variants_popover {
+            Some(emoji) if self.favorites_filtered.contains(&emoji) => (Some(emoji), None),
+            variants_popover => (None, variants_popover),
+        };
         if !self.favorites_filtered.is_empty() {
-            let flex_row_history = self.emojis_flex(self.favorites_filtered.iter().copied());
+            let flex_row_history = self.emojis_flex(
+                self.favorites_filtered.iter().copied(),
+                favorites_popover,
+                false,
+            );
             emojis_section = emojis_section.push(flex_row_history);
+        }
+        if !self.snippets_filtered.is_empty() || !self.favorites_filtered.is_empty() {
             emojis_section = emojis_section.push(widget::divider::horizontal::default());
         }
 
-        let flex_row = self.emojis_flex(self.emojis_filtered.iter().copied());
+        // collections are picked by hand, so their skin tones are never grouped
+        let grouped = self.selected_collection.is_none()
+            && self.config.active_skin_tone_filter() == SkinToneFilter::Grouped;
+        let flex_row = self.emojis_flex(
+            self.emojis_filtered.iter().copied(),
+            emojis_popover,
+            grouped,
+        );
         emojis_section = emojis_section.push(flex_row);
 
         let emojis_section_container = emojis_section
@@ -581,11 +1779,25 @@ impl Window {
 
     fn preview(&self) -> widget::Container<'_, Message, Theme> {
         let preview_emoji_opt = self.emoji_selected();
+        let preview_snippet_opt = self
+            .snippet_hovered
+            .and_then(|idx| self.config.snippets.get(idx));
         let mut preview_row = widget::row()
             .spacing(cosmic::theme::active().cosmic().space_xxs())
             .align_items(Alignment::Center);
-        match preview_emoji_opt {
-            Some(preview_emoji) => {
+        match (preview_snippet_opt, preview_emoji_opt) {
+            (Some(preview_snippet), _) => {
+                let snippet_txt = widget::text(preview_snippet.text.as_str())
+                    .size(35)
+                    .height(50)
+                    .font(self.font_family)
+                    .shaping(iced_core::text::Shaping::Advanced)
+                    .wrap(iced::widget::text::Wrap::None)
+                    .vertical_alignment(alignment::Vertical::Center);
+                preview_row = preview_row.push(snippet_txt);
+                preview_row = preview_row.push(widget::text::body(preview_snippet.name.as_str()));
+            }
+            (None, Some(preview_emoji)) => {
                 let emoji_txt = widget::text(preview_emoji.as_str())
                     .size(35)
                     .width(50)
@@ -601,22 +1813,41 @@ impl Window {
                 let emoji_name = self.emoji_name_trimmed(preview_emoji);
 
                 name_column = name_column.push(widget::text::body(emoji_name));
-                if let Some(shortcode) = preview_emoji.shortcode() {
+                let zwj_components = utils::zwj_components(preview_emoji);
+                if !zwj_components.is_empty() {
+                    name_column = name_column.push(self.zwj_decomposition(zwj_components));
+                } else if let Some(shortcode) = preview_emoji.shortcode() {
                     name_column = name_column.push(widget::text::caption(shortcode))
                 }
                 preview_row = preview_row.push(name_column);
+                preview_row = preview_row.push(
+                    widget::button::icon(
+                        widget::icon::from_name("view-more-symbolic").symbolic(true),
+                    )
+                    .tooltip(fl!("more-actions"))
+                    .on_press(Message::Page(Page::ContextMenu(preview_emoji))),
+                );
             }
-            None => {
-                let group_str = self
-                    .selected_group
-                    .map_or_else(|| fl!("emojis-and-favorites"), group_string);
+            (None, None) => {
+                let collection_name = self
+                    .selected_collection
+                    .and_then(|idx| self.config.collections.get(idx))
+                    .map(|collection| collection.name.clone());
+                let group_str = collection_name.unwrap_or_else(|| {
+                    self.selected_group
+                        .map_or_else(|| fl!("emojis-and-favorites"), group_string)
+                });
                 preview_row = preview_row.push(widget::text::title1(group_str));
             }
         }
-        if show_color_buttons(self.selected_group) {
-            let color_buttons = self.color_buttons();
+        if self.selected_collection.is_none() && show_color_buttons(self.selected_group) {
+            let filter_buttons = widget::column::with_capacity(2)
+                .spacing(4)
+                .align_items(Alignment::End)
+                .push(self.color_buttons())
+                .push(self.variant_filter_buttons());
             preview_row = preview_row.push(widget::horizontal_space(Length::Fill));
-            preview_row = preview_row.push(color_buttons);
+            preview_row = preview_row.push(filter_buttons);
         }
         // use cosmic::prelude::ElementExt;
         let center_y = widget::container(preview_row)
@@ -644,13 +1875,33 @@ impl Window {
     }
 
     fn nav_move_right(&mut self) -> Command<cosmic::app::Message<Message>> {
+        let collections_len = self.config.collections.len();
+        if let Some(idx) = self.selected_collection {
+            if idx + 1 < collections_len {
+                return self.update_collection(idx + 1);
+            }
+            return self.update_group(None);
+        }
         let mut key = nav::key_from_group(self.selected_group);
+        if key >= b'9' && collections_len > 0 {
+            return self.update_collection(0);
+        }
         key = if key >= b'9' { b'0' } else { key + 1 };
         return self.update_group(nav::group_from_key(key));
     }
 
     fn nav_move_left(&mut self) -> Command<cosmic::app::Message<Message>> {
+        let collections_len = self.config.collections.len();
+        if let Some(idx) = self.selected_collection {
+            if idx > 0 {
+                return self.update_collection(idx - 1);
+            }
+            return self.update_group(nav::group_from_key(b'9'));
+        }
         let mut key = nav::key_from_group(self.selected_group);
+        if key <= b'0' && collections_len > 0 {
+            return self.update_collection(collections_len - 1);
+        }
         key = if key <= b'0' { b'9' } else { key - 1 };
         return self.update_group(nav::group_from_key(key));
     }
@@ -661,6 +1912,60 @@ macro_rules! icon {
     }};
 }
 
+/// Reads a config value from disk instead of the cached `current` value,
+/// so that updates of other applet instances are merged instead of overwritten.
+fn stored<T: DeserializeOwned + Clone>(
+    config_handler: Option<&cosmic_config::Config>,
+    key: &str,
+    current: &T,
+) -> T {
+    let Some(config_handler) = config_handler else {
+        return current.clone();
+    };
+    match config_handler.get(key) {
+        Ok(value) => value,
+        Err(err) => {
+            eprintln!("failed to read config {:?}: {}", key, err);
+            current.clone()
+        }
+    }
+}
+
+/// Writes `text` to the clipboard, together with `html` for rich text editors.
+fn clipboard_write<T>(text: String, html: Option<String>) -> Command<T> {
+    match html {
+        Some(html) => iced::clipboard::write_data(HtmlText { html, text }),
+        None => iced::clipboard::write(text),
+    }
+}
+
+fn page_header<'a>(title: impl Into<Cow<'a, str>>) -> widget::Row<'a, Message> {
+    let back_btn =
+        widget::button::icon(widget::icon::from_name("go-previous-symbolic").symbolic(true))
+            .on_press(Message::Page(Page::Emojis));
+    widget::row::with_capacity(3)
+        .spacing(cosmic::theme::active().cosmic().space_xxs())
+        .align_items(Alignment::Center)
+        .push(back_btn)
+        .push(widget::text::title4(title))
+}
+
+fn group_button_style(is_selected: bool) -> cosmic::theme::Button {
+    use crate::style_copy::button;
+    cosmic::theme::Button::Custom {
+        active: Box::new(move |focused, theme| {
+            button::active(theme, focused, is_selected, button::Button::Icon)
+        }),
+        pressed: Box::new(move |focused, theme| {
+            button::pressed(theme, focused, is_selected, button::Button::Icon)
+        }),
+        hovered: Box::new(move |focused, theme| {
+            button::hovered(theme, focused, is_selected, button::Button::Icon)
+        }),
+        disabled: Box::new(|_theme| widget::button::Appearance::new()),
+    }
+}
+
 fn show_color_buttons(group: Option<emojis::Group>) -> bool {
     use emojis::Group::*;
     return matches!(group, None | Some(PeopleAndBody));
@@ -696,3 +2001,29 @@ fn group_string(group: emojis::Group) -> String {
         emojis::Group::Flags => fl!("flags"),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmic::cosmic_config::ConfigSet;
+
+    #[test]
+    fn stored_merges_other_instances() {
+        let this = crate::config::test_config_handler("StoredMergesOtherInstances");
+        let other = crate::config::test_config_handler("StoredMergesOtherInstances");
+
+        let cached: VecDeque<&'static emojis::Emoji> = VecDeque::new();
+        let grinning = emojis::get("😀").unwrap();
+        let party = emojis::get("🎉").unwrap();
+        other.set("last_used", VecDeque::from([grinning])).unwrap();
+
+        let mut last_used = stored(Some(&this), "last_used", &cached);
+        last_used.push_front(party);
+        this.set("last_used", last_used).unwrap();
+
+        let merged: VecDeque<&'static emojis::Emoji> = other.get("last_used").unwrap();
+        assert_eq!(merged, [party, grinning]);
+        // without a config handler the cached value is used
+        assert_eq!(stored(None, "last_used", &cached), cached);
+    }
+}
@@ -0,0 +1,141 @@
+use super::{page_header, style, Message, Window};
+use crate::config::{ColorButton, SkinToneMode};
+use crate::fl;
+
+use cosmic::iced::{Alignment, Length};
+use cosmic::{widget, Apply};
+
+/// Skin tone modes with a single bit, from `DEFAULT` up to `DARK_AND_MEDIUM_DARK`.
+const SKIN_TONE_MODE_BITS: u32 = 28;
+
+/// Names of the skin tone modes offered in the dropdown, indexed by their bit.
+pub(super) fn skin_tone_mode_names() -> Vec<String> {
+    let skin_tones = [
+        fl!("skin-tone-light"),
+        fl!("skin-tone-medium-light"),
+        fl!("skin-tone-medium"),
+        fl!("skin-tone-medium-dark"),
+        fl!("skin-tone-dark"),
+    ];
+    let mut names = Vec::with_capacity(SKIN_TONE_MODE_BITS as usize);
+    names.push(fl!("skin-tone-default"));
+    names.extend(skin_tones.iter().cloned());
+    names.push(fl!("no-skin-tone"));
+    names.push(fl!("other-skin-tones"));
+    // same order as the exact skin tone bits
+    for first in &skin_tones {
+        for second in skin_tones.iter().filter(|&second| second != first) {
+            names.push(fl!(
+                "skin-tone-pair",
+                first = first.as_str(),
+                second = second.as_str()
+            ));
+        }
+    }
+    names
+}
+
+fn skin_tone_mode_idx(skin_tone_mode: SkinToneMode) -> Option<usize> {
+    let bits = skin_tone_mode.bits();
+    (bits.is_power_of_two() && bits.trailing_zeros() < SKIN_TONE_MODE_BITS)
+        .then(|| bits.trailing_zeros() as usize)
+}
+
+impl Window {
+    pub(super) fn color_button_editor(&self) -> widget::Column<'_, Message> {
+        let spacing = cosmic::theme::active().cosmic().space_xxs();
+        let color_buttons = &self.config.color_buttons;
+
+        let mut list = widget::column::with_capacity(color_buttons.len()).spacing(spacing);
+        for (idx, color_button) in color_buttons.iter().enumerate() {
+            let edited = self.color_button_edited == Some(idx);
+            let mode_name = match skin_tone_mode_idx(color_button.skin_tone_mode) {
+                Some(mode_idx) => self.skin_tone_mode_names[mode_idx].clone(),
+                None => fl!("custom-skin-tone-mode"),
+            };
+            let row = widget::row::with_capacity(6)
+                .spacing(spacing)
+                .align_items(Alignment::Center)
+                .push(
+                    widget::button(widget::horizontal_space(0.1))
+                        .width(20)
+                        .height(20)
+                        .style(style::color_button_style(color_button.color, edited))
+                        .on_press(Message::EditColorButton(idx)),
+                )
+                .push(widget::text::body(mode_name))
+                .push(widget::horizontal_space(Length::Fill))
+                .push(
+                    widget::button::icon(widget::icon::from_name("go-up-symbolic").symbolic(true))
+                        .on_press_maybe((idx > 0).then(|| Message::MoveColorButton(idx, idx - 1))),
+                )
+                .push(
+                    widget::button::icon(
+                        widget::icon::from_name("go-down-symbolic").symbolic(true),
+                    )
+                    .on_press_maybe(
+                        (idx + 1 < color_buttons.len())
+                            .then(|| Message::MoveColorButton(idx, idx + 1)),
+                    ),
+                )
+                .push(
+                    widget::button::icon(
+                        widget::icon::from_name("user-trash-symbolic").symbolic(true),
+                    )
+                    .on_press(Message::RemoveColorButton(idx)),
+                );
+            list = list.push(row);
+            if color_button.skin_tone_mode.has_reserved_bits() {
+                list = list.push(widget::text::caption(fl!("reserved-bits")));
+            }
+            if edited {
+                list = list.push(self.color_button_fields(idx, color_button));
+            }
+        }
+        let list = widget::scrollable(list)
+            .height(Length::Fill)
+            .width(Length::Fill)
+            .apply(widget::container)
+            .height(400);
+
+        widget::column::with_capacity(3)
+            .spacing(spacing)
+            .push(page_header(fl!("color-buttons")))
+            .push(list)
+            .push(
+                widget::button::standard(fl!("add-color-button")).on_press(Message::AddColorButton),
+            )
+    }
+
+    fn color_button_fields(
+        &self,
+        idx: usize,
+        color_button: &ColorButton,
+    ) -> widget::Column<'_, Message> {
+        let spacing = cosmic::theme::active().cosmic().space_xxs();
+        let mut fields = widget::column::with_capacity(4).spacing(spacing);
+
+        let channels = [fl!("red"), fl!("green"), fl!("blue")];
+        for (channel, label) in channels.into_iter().enumerate() {
+            let value = (color_button.color[channel] * 255.).round().clamp(0., 255.) as u8;
+            let row = widget::row::with_capacity(3)
+                .spacing(spacing)
+                .align_items(Alignment::Center)
+                .push(widget::text::caption(label).width(50))
+                .push(widget::slider(0..=255, value, move |value| {
+                    Message::ColorButtonChannel(idx, channel, value)
+                }))
+                .push(widget::text::caption(value.to_string()).width(30));
+            fields = fields.push(row);
+        }
+
+        let skin_tone_mode = widget::dropdown(
+            &self.skin_tone_mode_names,
+            skin_tone_mode_idx(color_button.skin_tone_mode),
+            move |mode_idx| {
+                Message::ColorButtonMode(idx, SkinToneMode::from_bits_retain(1 << mode_idx))
+            },
+        );
+        fields.push(skin_tone_mode)
+    }
+}
@@ -0,0 +1,84 @@
+use super::{style, Message, Window};
+use crate::fl;
+
+use cosmic::iced::{alignment, Alignment, Length};
+use cosmic::{iced, iced_core, widget};
+
+impl Window {
+    pub(super) fn compose_bar(&self) -> widget::Row<'_, Message> {
+        let spacing = cosmic::theme::active().cosmic().space_xxs();
+
+        let mut composed =
+            widget::row::with_capacity(self.compose.len() + 1).align_items(Alignment::Center);
+        for (idx, text) in self.compose.iter().enumerate() {
+            if idx == self.compose_cursor {
+                composed = composed.push(compose_cursor());
+            }
+            let text_txt = widget::text(text.as_str())
+                .size(20)
+                .height(30)
+                .font(self.font_family)
+                .shaping(iced_core::text::Shaping::Advanced)
+                .wrap(iced::widget::text::Wrap::None)
+                .vertical_alignment(alignment::Vertical::Center);
+            // clicking an emoji places the cursor behind it
+            composed = composed.push(
+                widget::button(text_txt)
+                    .padding(0)
+                    .style(cosmic::theme::Button::Transparent)
+                    .on_press(Message::ComposeCursor(idx + 1)),
+            );
+        }
+        if self.compose_cursor == self.compose.len() {
+            composed = composed.push(compose_cursor());
+        }
+        let composed = widget::scrollable(composed)
+            .direction(widget::scrollable::Direction::Horizontal(
+                widget::scrollable::Properties::default(),
+            ))
+            .width(Length::Fill);
+
+        let cursor_left = self.compose_cursor.checked_sub(1);
+        let cursor_right = Some(self.compose_cursor + 1).filter(|&c| c <= self.compose.len());
+        widget::row::with_capacity(7)
+            .spacing(spacing)
+            .align_items(Alignment::Center)
+            .push(composed)
+            .push(
+                widget::button::icon(
+                    widget::icon::from_name("go-previous-symbolic").symbolic(true),
+                )
+                .on_press_maybe(cursor_left.map(Message::ComposeCursor)),
+            )
+            .push(
+                widget::button::icon(widget::icon::from_name("go-next-symbolic").symbolic(true))
+                    .on_press_maybe(cursor_right.map(Message::ComposeCursor)),
+            )
+            .push(
+                widget::button::icon(widget::icon::from_name("edit-clear-symbolic").symbolic(true))
+                    .on_press_maybe(cursor_left.map(|_| Message::ComposeBackspace)),
+            )
+            .push(
+                widget::button::icon(widget::icon::from_name("edit-undo-symbolic").symbolic(true))
+                    .on_press_maybe(
+                        (!self.compose_undo.is_empty()).then_some(Message::ComposeUndo),
+                    ),
+            )
+            .push(
+                widget::button::suggested(fl!("copy"))
+                    .on_press_maybe((!self.compose.is_empty()).then_some(Message::ComposeCopy)),
+            )
+            .push(
+                widget::button::icon(
+                    widget::icon::from_name("window-close-symbolic").symbolic(true),
+                )
+                .on_press(Message::ComposeClear),
+            )
+    }
+}
+
+fn compose_cursor() -> widget::Container<'static, Message, cosmic::Theme> {
+    widget::container(widget::vertical_space(20))
+        .width(2)
+        .style(style::compose_cursor_style())
+}
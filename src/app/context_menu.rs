@@ -0,0 +1,271 @@
+use super::{menu, Copyable, Message, Page, Window};
+use crate::config::{ClickMode, Presentation, MAX_COLLECTIONS};
+use crate::copy_format::CopyFormat;
+use crate::render;
+use crate::{fl, utils};
+
+use cosmic::iced::{alignment, Alignment, Length};
+use cosmic::{iced, iced_core, widget, Apply, Element};
+
+impl Window {
+    pub(super) fn context_menu(
+        &self,
+        emoji: &'static emojis::Emoji,
+    ) -> widget::Column<'_, Message> {
+        let spacing = cosmic::theme::active().cosmic().space_xxs();
+        let mut context_menu = widget::column::with_capacity(3).spacing(spacing);
+
+        let back_btn =
+            widget::button::icon(widget::icon::from_name("go-previous-symbolic").symbolic(true))
+                .on_press(Message::Page(Page::Emojis));
+        let emoji_txt = self.context_menu_emoji(emoji.as_str(), 35);
+        let header = widget::row::with_capacity(3)
+            .spacing(spacing)
+            .align_items(Alignment::Center)
+            .push(back_btn)
+            .push(emoji_txt)
+            .push(widget::text::body(self.emoji_name_trimmed(emoji)));
+        context_menu = context_menu.push(header);
+
+        let mut actions = widget::column::with_capacity(2).spacing(spacing);
+        if self.config.last_used.contains(&emoji) {
+            actions = actions.push(
+                widget::button::standard(fl!("remove-from-recent"))
+                    .on_press(Message::RemoveFromHistory(emoji)),
+            );
+        }
+        if utils::is_multi_person(emoji) {
+            actions = actions.push(
+                widget::button::standard(fl!("compose-skin-tones"))
+                    .on_press(Message::OpenToneComposer(emoji)),
+            );
+        }
+        actions = actions.push(widget::button::standard(fl!("add-to-compose")).on_press(
+            Message::EmojiCopy(Copyable::Emoji(emoji), ClickMode::COMPOSE),
+        ));
+        let zwj_components = utils::zwj_components(emoji);
+        if !zwj_components.is_empty() {
+            actions = actions.push(
+                widget::button::standard(fl!("edit-in-zwj-builder"))
+                    .on_press(Message::ZwjBuilder(zwj_components)),
+            );
+        }
+        actions = actions.push(self.copy_as_section(emoji));
+        actions = actions.push(self.presentation_section(emoji));
+        actions = actions.push(self.image_section(emoji));
+        actions = actions.push(self.collections_section(emoji));
+
+        let actions = widget::scrollable(actions)
+            .height(Length::Fill)
+            .width(Length::Fill)
+            .apply(widget::container)
+            .height(500);
+        context_menu = context_menu.push(actions);
+        context_menu
+    }
+
+    fn collections_section(&self, emoji: &'static emojis::Emoji) -> widget::Column<'_, Message> {
+        let spacing = cosmic::theme::active().cosmic().space_xxs();
+        let mut section = widget::column::with_capacity(self.config.collections.len() + 2)
+            .spacing(spacing)
+            .push(widget::text::heading(fl!("collections")));
+
+        for (idx, collection) in self.config.collections.iter().enumerate() {
+            let in_collection = collection.emojis.contains(&emoji);
+            let toggle_icon = if in_collection {
+                "list-remove-symbolic"
+            } else {
+                "list-add-symbolic"
+            };
+            let use_as_icon = widget::button::icon(
+                widget::icon::from_name("image-x-generic-symbolic").symbolic(true),
+            )
+            .tooltip(fl!("use-as-icon"))
+            .on_press_maybe(
+                (collection.icon != emoji).then_some(Message::CollectionIcon(idx, emoji)),
+            );
+            let row = widget::row::with_capacity(6)
+                .spacing(spacing)
+                .align_items(Alignment::Center)
+                .push(self.context_menu_emoji(collection.icon.as_str(), 20))
+                .push(widget::text::body(collection.name.as_str()))
+                .push(widget::horizontal_space(Length::Fill))
+                .push(use_as_icon)
+                .push(
+                    widget::button::icon(widget::icon::from_name(toggle_icon).symbolic(true))
+                        .on_press(Message::ToggleInCollection(idx, emoji)),
+                )
+                .push(
+                    widget::button::icon(
+                        widget::icon::from_name("user-trash-symbolic").symbolic(true),
+                    )
+                    .on_press(Message::RemoveCollection(idx)),
+                );
+            section = section.push(row);
+        }
+
+        if self.config.collections.len() >= MAX_COLLECTIONS {
+            return section;
+        }
+        let new_collection = widget::row::with_capacity(2)
+            .spacing(spacing)
+            .align_items(Alignment::Center)
+            .push(
+                widget::text_input(fl!("new-collection"), &self.collection_name)
+                    .on_input(Message::CollectionName)
+                    .on_submit(Message::NewCollection(emoji)),
+            )
+            .push(
+                widget::button::icon(widget::icon::from_name("list-add-symbolic").symbolic(true))
+                    .on_press(Message::NewCollection(emoji)),
+            );
+        section = section.push(new_collection);
+        section
+    }
+
+    fn copy_as_section(&self, emoji: &'static emojis::Emoji) -> widget::Column<'_, Message> {
+        let spacing = cosmic::theme::active().cosmic().space_xxs();
+        let mut section = widget::column::with_capacity(CopyFormat::ALL.len() + 1)
+            .spacing(spacing)
+            .push(widget::text::heading(fl!("copy-as")));
+
+        let name = self.emoji_name_localized(emoji);
+        // the format picked here replaces the one of the left click action
+        let click_mode = CopyFormat::ALL
+            .into_iter()
+            .fold(self.pick_click_mode(), |click_mode, format| {
+                click_mode.difference(format.click_mode())
+            });
+        for format in CopyFormat::ALL {
+            let Some(formatted) = format.format(emoji.as_str(), emoji.shortcode(), Some(name))
+            else {
+                continue;
+            };
+            let label = match format {
+                CopyFormat::Shortcode => fl!("format-shortcode"),
+                CopyFormat::Codepoint => fl!("format-codepoint"),
+                CopyFormat::HtmlEntity => fl!("format-html-entity"),
+                CopyFormat::Rust => fl!("format-rust"),
+                CopyFormat::Json => fl!("format-json"),
+                CopyFormat::Python => fl!("format-python"),
+                CopyFormat::UrlEncoded => fl!("format-url-encoded"),
+                CopyFormat::Name => fl!("format-name"),
+            };
+            let row = widget::row::with_capacity(4)
+                .spacing(spacing)
+                .align_items(Alignment::Center)
+                .push(widget::text::body(label))
+                .push(widget::horizontal_space(Length::Fill))
+                .push(widget::text::caption(formatted))
+                .push(
+                    widget::button::icon(
+                        widget::icon::from_name("edit-copy-symbolic").symbolic(true),
+                    )
+                    .on_press(Message::EmojiCopy(
+                        Copyable::Emoji(emoji),
+                        click_mode | format.click_mode(),
+                    )),
+                );
+            section = section.push(row);
+        }
+        section
+    }
+
+    fn presentation_section(&self, emoji: &'static emojis::Emoji) -> widget::Column<'_, Message> {
+        let spacing = cosmic::theme::active().cosmic().space_xxs();
+        let mut section = widget::column::with_capacity(Presentation::ALL.len())
+            .spacing(spacing)
+            .push(widget::text::heading(fl!("presentation")));
+
+        // the presentation picked here replaces the one of the left click action
+        let click_mode = Presentation::ALL
+            .into_iter()
+            .fold(self.pick_click_mode(), |click_mode, presentation| {
+                click_mode.difference(presentation.click_mode())
+            });
+        let mut variants = Vec::with_capacity(Presentation::ALL.len());
+        for presentation in Presentation::ALL.into_iter().skip(1) {
+            let text = utils::with_presentation(emoji.as_str(), presentation);
+            // only emojis with variation selectors have more than one presentation
+            if variants.contains(&text) {
+                continue;
+            }
+            let codepoints = text
+                .chars()
+                .map(|c| format!("U+{:04X}", c as u32))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let row = widget::row::with_capacity(4)
+                .spacing(spacing)
+                .align_items(Alignment::Center)
+                .push(widget::text::body(menu::presentation_label(presentation)))
+                .push(widget::horizontal_space(Length::Fill))
+                .push(widget::text::caption(codepoints))
+                .push(
+                    widget::button::icon(
+                        widget::icon::from_name("edit-copy-symbolic").symbolic(true),
+                    )
+                    .on_press(Message::EmojiCopy(
+                        Copyable::Emoji(emoji),
+                        click_mode | presentation.click_mode(),
+                    )),
+                );
+            section = section.push(row);
+            variants.push(text);
+        }
+        if variants.len() < 2 {
+            return widget::column::with_capacity(0);
+        }
+        section
+    }
+
+    fn image_section(&self, emoji: &'static emojis::Emoji) -> widget::Column<'_, Message> {
+        let spacing = cosmic::theme::active().cosmic().space_xxs();
+        let mut section = widget::column::with_capacity(3)
+            .spacing(spacing)
+            .push(widget::text::heading(fl!("image")));
+
+        let size_idx = render::SIZES
+            .iter()
+            .position(|&size| size == self.image_size());
+        let row = widget::row::with_capacity(3)
+            .spacing(spacing)
+            .align_items(Alignment::Center)
+            .push(widget::dropdown(
+                &self.image_size_names,
+                size_idx,
+                |size_idx| Message::ImageSize(render::SIZES[size_idx]),
+            ))
+            .push(
+                widget::button::standard(fl!("copy-as-image")).on_press(Message::CopyImage(emoji)),
+            )
+            .push(widget::button::standard(fl!("save-as-png")).on_press(Message::SaveImage(emoji)));
+        section = section.push(row);
+
+        match &self.image_export {
+            Some(Ok(path)) => {
+                let path = path.display().to_string();
+                section = section.push(widget::text::caption(fl!("exported-to", path = path)));
+            }
+            Some(Err(err)) => {
+                section = section.push(widget::text::caption(fl!(
+                    "export-failed",
+                    error = err.as_str()
+                )));
+            }
+            None => {}
+        }
+        section
+    }
+
+    fn context_menu_emoji(&self, emoji: &str, size: u16) -> Element<'static, Message> {
+        widget::text(emoji.to_string())
+            .size(size)
+            .font(self.font_family)
+            .shaping(iced_core::text::Shaping::Advanced)
+            .wrap(iced::widget::text::Wrap::None)
+            .horizontal_alignment(alignment::Horizontal::Center)
+            .vertical_alignment(alignment::Vertical::Center)
+            .into()
+    }
+}
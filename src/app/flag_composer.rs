@@ -0,0 +1,95 @@
+use super::{page_header, Copyable, Message, Window};
+use crate::config::ClickMode;
+use crate::{fl, flag};
+
+use cosmic::iced::{alignment, Alignment};
+use cosmic::{iced, iced_core, widget, Element};
+
+impl Window {
+    pub(super) fn flag_composer(&self) -> widget::Column<'_, Message> {
+        let spacing = cosmic::theme::active().cosmic().space_xxs();
+        let composed = flag::compose(&self.flag_code);
+
+        let mut flag_composer = widget::column::with_capacity(5)
+            .spacing(spacing)
+            .push(page_header(fl!("flag-composer")));
+
+        let mut flag_code = widget::text_input(fl!("flag-code"), &self.flag_code)
+            .on_input(Message::FlagCode)
+            .on_paste(Message::FlagCode);
+        if let Some(composed) = &composed {
+            flag_code = flag_code.on_submit(Message::EmojiCopy(
+                Copyable::from(composed.clone()),
+                self.pick_click_mode(),
+            ));
+        }
+        flag_composer = flag_composer.push(flag_code);
+
+        let preview_txt = composed
+            .as_ref()
+            .map_or("", |composed| composed.text.as_str());
+        let preview = widget::text(preview_txt.to_string())
+            .size(50)
+            .height(70)
+            .font(self.font_family)
+            .shaping(iced_core::text::Shaping::Advanced)
+            .wrap(iced::widget::text::Wrap::None)
+            .vertical_alignment(alignment::Vertical::Center);
+        flag_composer = flag_composer.push(preview);
+
+        let status = match &composed {
+            _ if self.flag_code.trim().is_empty() => fl!("flag-code-example"),
+            None => fl!("flag-invalid-code"),
+            Some(flag::Flag {
+                emoji: Some(emoji), ..
+            }) => self.emoji_name_localized(emoji).to_string(),
+            Some(flag::Flag { emoji: None, .. }) => fl!("flag-not-rgi"),
+        };
+        flag_composer = flag_composer.push(widget::text::caption(status));
+
+        let copy_btn =
+            widget::button::suggested(fl!("copy")).on_press_maybe(composed.map(|composed| {
+                Message::EmojiCopy(Copyable::from(composed), self.pick_click_mode())
+            }));
+        flag_composer = flag_composer.push(copy_btn);
+        flag_composer
+    }
+
+    /// Shows flags searched with `flag:` which are not in the grid, as they are not RGI.
+    pub(super) fn flag_search_result(&self, code: &str) -> Option<Element<'static, Message>> {
+        let spacing = cosmic::theme::active().cosmic().space_xxs();
+        let composed = match flag::compose(code) {
+            Some(flag::Flag { emoji: Some(_), .. }) => return None,
+            Some(composed) => composed,
+            None if code.trim().is_empty() => {
+                return Some(widget::text::caption(fl!("flag-code-example")).into())
+            }
+            None => return Some(widget::text::caption(fl!("flag-invalid-code")).into()),
+        };
+
+        let flag_txt = widget::text(composed.text.clone())
+            .size(25)
+            .width(35)
+            .height(35)
+            .font(self.font_family)
+            .shaping(iced_core::text::Shaping::Advanced)
+            .wrap(iced::widget::text::Wrap::None)
+            .horizontal_alignment(alignment::Horizontal::Center)
+            .vertical_alignment(alignment::Vertical::Center);
+        let mut flag_btn: widget::Button<'_, Message> =
+            widget::button(flag_txt).style(cosmic::theme::Button::Transparent);
+        let left_click_action = self.config.left_click_action;
+        if left_click_action != ClickMode::NONE {
+            flag_btn = flag_btn.on_press(Message::EmojiCopy(
+                Copyable::from(composed),
+                left_click_action,
+            ));
+        }
+        let flag_search_result = widget::row::with_capacity(2)
+            .spacing(spacing)
+            .align_items(Alignment::Center)
+            .push(flag_btn)
+            .push(widget::text::caption(fl!("flag-not-rgi")));
+        Some(flag_search_result.into())
+    }
+}
@@ -0,0 +1,181 @@
+use super::{page_header, Message, Page, Window};
+use crate::config::{Presentation, SkinToneFilter, SkinToneMatching};
+use crate::export::ExportFormat;
+use crate::{fl, virtual_keyboard};
+
+use cosmic::iced::{Alignment, Length};
+use cosmic::{widget, Apply};
+
+impl Window {
+    pub(super) fn menu(&self) -> widget::Column<'_, Message> {
+        let spacing = cosmic::theme::active().cosmic().space_xxs();
+        let mut menu = widget::column::with_capacity(2)
+            .spacing(spacing)
+            .push(page_header(fl!("menu")));
+
+        let mut entries = widget::column::with_capacity(7).spacing(spacing);
+        entries =
+            entries.push(widget::button::standard(fl!("snippets")).on_press(Message::OpenSnippets));
+        entries = entries.push(
+            widget::button::standard(fl!("usage-statistics")).on_press(Message::Page(Page::Stats)),
+        );
+        entries = entries.push(
+            widget::button::standard(fl!("zwj-builder")).on_press(Message::ZwjBuilder(Vec::new())),
+        );
+        entries = entries.push(
+            widget::button::standard(fl!("flag-composer"))
+                .on_press(Message::Page(Page::FlagComposer)),
+        );
+        entries = entries.push(
+            widget::button::standard(fl!("text-transform"))
+                .on_press(Message::Page(Page::TextTransform)),
+        );
+        entries = entries.push(
+            widget::button::standard(fl!("color-buttons"))
+                .on_press(Message::Page(Page::ColorButtons)),
+        );
+        entries = entries.push(self.skin_tones_section());
+        entries = entries.push(self.presentation_section());
+        entries = entries.push(self.paste_section());
+        entries = entries.push(self.history_section());
+        entries = entries.push(self.export_section());
+
+        let entries = widget::scrollable(entries)
+            .height(Length::Fill)
+            .width(Length::Fill)
+            .apply(widget::container)
+            .height(500);
+        menu = menu.push(entries);
+        menu
+    }
+
+    fn export_section(&self) -> widget::Column<'_, Message> {
+        let spacing = cosmic::theme::active().cosmic().space_xxs();
+        let mut buttons = widget::row::with_capacity(ExportFormat::ALL.len()).spacing(spacing);
+        for format in ExportFormat::ALL {
+            let label = match format {
+                ExportFormat::XCompose => fl!("export-xcompose"),
+                ExportFormat::Espanso => fl!("export-espanso"),
+            };
+            buttons = buttons
+                .push(widget::button::standard(label).on_press(Message::ExportFavorites(format)));
+        }
+        let mut section = widget::column::with_capacity(4)
+            .spacing(spacing)
+            .push(widget::text::heading(fl!("export-favorites")))
+            .push(widget::text::caption(fl!("export-favorites-description")))
+            .push(buttons);
+        match &self.favorites_export {
+            Some(Ok(path)) => {
+                let path = path.display().to_string();
+                section = section.push(widget::text::caption(fl!("exported-to", path = path)));
+            }
+            Some(Err(err)) => {
+                section = section.push(widget::text::caption(fl!(
+                    "export-failed",
+                    error = err.as_str()
+                )));
+            }
+            None => {}
+        }
+        section
+    }
+
+    fn history_section(&self) -> widget::Column<'_, Message> {
+        let spacing = cosmic::theme::active().cosmic().space_xxs();
+        let pause_history = widget::row::with_capacity(2)
+            .spacing(spacing)
+            .align_items(Alignment::Center)
+            .push(widget::text::body(fl!("pause-history")))
+            .push(widget::horizontal_space(Length::Fill))
+            .push(widget::toggler(
+                None,
+                self.history_paused,
+                Message::PauseHistory,
+            ));
+        let clear_history = widget::button::destructive(fl!("clear-history"))
+            .on_press_maybe((!self.config.last_used.is_empty()).then_some(Message::ClearHistory));
+
+        widget::column::with_capacity(3)
+            .spacing(spacing)
+            .push(widget::text::heading(fl!("history")))
+            .push(pause_history)
+            .push(clear_history)
+    }
+
+    fn presentation_section(&self) -> widget::Column<'_, Message> {
+        let spacing = cosmic::theme::active().cosmic().space_xxs();
+        let mut section = widget::column::with_capacity(Presentation::ALL.len() + 1)
+            .spacing(spacing)
+            .push(widget::text::heading(fl!("presentation")));
+        for presentation in Presentation::ALL {
+            section = section.push(widget::radio(
+                presentation_label(presentation),
+                presentation,
+                Some(self.config.presentation),
+                Message::Presentation,
+            ));
+        }
+        section
+    }
+
+    fn paste_section(&self) -> widget::Column<'_, Message> {
+        let spacing = cosmic::theme::active().cosmic().space_xxs();
+        let mut section = widget::column::with_capacity(3)
+            .spacing(spacing)
+            .push(widget::text::heading(fl!("auto-paste")))
+            .push(
+                widget::text_input(fl!("paste-chord"), &self.paste_chord)
+                    .on_input(Message::PasteChord),
+            );
+        if let Err(err) = virtual_keyboard::parse_chord(&self.paste_chord) {
+            section = section.push(widget::text::caption(err));
+        }
+        section
+    }
+
+    fn skin_tones_section(&self) -> widget::Column<'_, Message> {
+        let spacing = cosmic::theme::active().cosmic().space_xxs();
+        let current = self.config.active_skin_tone_filter();
+        let chosen = match current {
+            SkinToneFilter::Chosen { .. } => current,
+            _ => SkinToneFilter::chosen(self.config.skin_tone_mode),
+        };
+        let mut section = widget::column::with_capacity(7)
+            .spacing(spacing)
+            .push(widget::text::heading(fl!("skin-tones")));
+        for (label, filter) in [
+            (fl!("skin-tones-default-only"), SkinToneFilter::DefaultOnly),
+            (fl!("skin-tones-chosen"), chosen),
+            (fl!("skin-tones-grouped"), SkinToneFilter::Grouped),
+        ] {
+            section = section.push(widget::radio(
+                label,
+                filter,
+                Some(current),
+                Message::SkinToneFilter,
+            ));
+        }
+        if let SkinToneFilter::Chosen { tones, .. } = current {
+            for (label, matching) in [
+                (fl!("match-every-person"), SkinToneMatching::EveryPerson),
+                (fl!("match-any-person"), SkinToneMatching::AnyPerson),
+                (fl!("match-exact"), SkinToneMatching::Exact),
+            ] {
+                let filter = SkinToneFilter::Chosen { tones, matching };
+                let radio = widget::radio(label, filter, Some(current), Message::SkinToneFilter);
+                section = section.push(widget::container(radio).padding([0, 0, 0, 20]));
+            }
+        }
+        section
+    }
+}
+
+pub(super) fn presentation_label(presentation: Presentation) -> String {
+    match presentation {
+        Presentation::AsListed => fl!("presentation-as-listed"),
+        Presentation::Emoji => fl!("presentation-emoji"),
+        Presentation::Text => fl!("presentation-text"),
+        Presentation::Minimal => fl!("presentation-minimal"),
+    }
+}
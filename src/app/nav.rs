@@ -36,6 +36,13 @@ pub(crate) fn key_from_group(group: Option<emojis::Group>) -> u8 {
     return group;
 }
 
+pub(crate) fn collection_from_key(key: u8) -> Option<usize> {
+    if key.is_ascii_lowercase() {
+        return Some((key - b'a') as usize);
+    }
+    return None;
+}
+
 pub(crate) fn subscription() -> Subscription<Message> {
     use cosmic::iced::event;
     cosmic::iced_futures::event::listen_with(|event, status| {
@@ -75,6 +82,11 @@ pub(crate) fn subscription() -> Subscription<Message> {
                 if key_character.len() == 1 && key_character.as_bytes()[0].is_ascii_digit() {
                     return Some(Message::Group(group_from_key(key_character.as_bytes()[0])));
                 }
+                if key_character.len() == 1 {
+                    if let Some(idx) = collection_from_key(key_character.as_bytes()[0]) {
+                        return Some(Message::Collection(idx));
+                    }
+                }
             }
             _ => {}
         }
@@ -0,0 +1,107 @@
+use super::{page_header, Copyable, Message, Window};
+use crate::config::ClickMode;
+use crate::{fl, widget_copy};
+
+use cosmic::iced::{alignment, Alignment, Length};
+use cosmic::{iced, iced_core, widget, Apply};
+
+impl Window {
+    pub(super) fn snippets(&self) -> widget::Column<'_, Message> {
+        let spacing = cosmic::theme::active().cosmic().space_xxs();
+        let mut snippets = widget::column::with_capacity(3)
+            .spacing(spacing)
+            .push(page_header(fl!("snippets")));
+
+        let snippet_name = widget::text_input(fl!("snippet-name"), &self.snippet_name)
+            .on_input(Message::SnippetName)
+            .on_submit(Message::SaveSnippet);
+        let snippet_text = widget::text_input(fl!("snippet-text"), &self.snippet_text)
+            .on_input(Message::SnippetText)
+            .on_paste(Message::SnippetText)
+            .on_submit(Message::SaveSnippet);
+        let save_btn = widget::button::suggested(fl!("save-snippet")).on_press_maybe(
+            (!self.snippet_name.trim().is_empty() && !self.snippet_text.is_empty())
+                .then_some(Message::SaveSnippet),
+        );
+        let new_snippet = widget::column::with_capacity(3)
+            .spacing(spacing)
+            .push(snippet_name)
+            .push(snippet_text)
+            .push(save_btn);
+        snippets = snippets.push(new_snippet);
+
+        let mut saved = widget::column::with_capacity(self.config.snippets.len()).spacing(spacing);
+        for (idx, snippet) in self.config.snippets.iter().enumerate() {
+            let row = widget::row::with_capacity(4)
+                .spacing(spacing)
+                .align_items(Alignment::Center)
+                .push(
+                    widget::text(snippet.text.as_str())
+                        .size(20)
+                        .font(self.font_family)
+                        .shaping(iced_core::text::Shaping::Advanced)
+                        .wrap(iced::widget::text::Wrap::None),
+                )
+                .push(widget::text::body(snippet.name.as_str()))
+                .push(widget::horizontal_space(Length::Fill))
+                .push(
+                    widget::button::icon(
+                        widget::icon::from_name("user-trash-symbolic").symbolic(true),
+                    )
+                    .on_press(Message::RemoveSnippet(idx)),
+                );
+            saved = saved.push(row);
+        }
+        let saved = widget::scrollable(saved)
+            .height(Length::Fill)
+            .width(Length::Fill)
+            .apply(widget::container)
+            .height(400);
+        snippets = snippets.push(saved);
+        snippets
+    }
+
+    pub(super) fn snippets_flex(&self) -> widget::FlexRow<'static, Message> {
+        let mut snippets_view = Vec::with_capacity(self.snippets_filtered.len());
+
+        let left_click_action = self.config.left_click_action;
+        let right_click_action = self.config.right_click_action;
+        let middle_click_action = self.config.middle_click_action;
+        for &idx in &self.snippets_filtered {
+            let Some(snippet) = self.config.snippets.get(idx) else {
+                continue;
+            };
+            let copyable = Copyable::Text(snippet.text.clone());
+            let snippet_txt = widget::text(snippet.text.clone())
+                .size(25)
+                .height(35)
+                .font(self.font_family)
+                .shaping(iced_core::text::Shaping::Advanced)
+                .wrap(iced::widget::text::Wrap::None)
+                .vertical_alignment(alignment::Vertical::Center);
+
+            let mut snippet_btn: widget::Button<'_, Message> =
+                widget::button(snippet_txt).style(cosmic::theme::Button::Transparent);
+            if left_click_action != ClickMode::NONE {
+                snippet_btn =
+                    snippet_btn.on_press(Message::EmojiCopy(copyable.clone(), left_click_action));
+            }
+            let mut snippet_mouse_area =
+                widget_copy::MouseArea::new(snippet_btn).on_enter(Message::SnippetHovered(idx));
+            if right_click_action != ClickMode::NONE {
+                snippet_mouse_area = snippet_mouse_area
+                    .on_right_release(Message::EmojiCopy(copyable.clone(), right_click_action));
+            }
+            if middle_click_action != ClickMode::NONE {
+                snippet_mouse_area = snippet_mouse_area
+                    .on_middle_release(Message::EmojiCopy(copyable, middle_click_action));
+            }
+            snippets_view.push(snippet_mouse_area.into());
+        }
+
+        widget::flex_row(snippets_view)
+            .row_spacing(0)
+            .column_spacing(0)
+            .width(Length::Fill)
+    }
+}
@@ -19,3 +19,25 @@ pub(crate) fn color_button_apperance(
     };
     return color_button;
 }
+
+pub(crate) fn color_button_style(color: [f32; 4], active: bool) -> cosmic::theme::Button {
+    cosmic::theme::Button::Custom {
+        active: Box::new(move |_selected, theme| {
+            color_button_apperance(color, Some(active), theme)
+        }),
+        disabled: Box::new(move |theme| color_button_apperance(color, None, theme)),
+        hovered: Box::new(move |_selected, theme| {
+            color_button_apperance(color, Some(active), theme)
+        }),
+        pressed: Box::new(move |_selected, theme| {
+            color_button_apperance(color, Some(active), theme)
+        }),
+    }
+}
+
+pub(crate) fn compose_cursor_style() -> cosmic::theme::Container {
+    cosmic::theme::Container::custom(|theme| widget::container::Appearance {
+        background: Some(iced::Color::from(theme.cosmic().accent_color()).into()),
+        ..Default::default()
+    })
+}
@@ -0,0 +1,72 @@
+use super::{page_header, Copyable, Message, Window};
+use crate::config::ClickMode;
+use crate::fl;
+use crate::text_transform::Transform;
+
+use cosmic::iced::alignment;
+use cosmic::{iced, iced_core, widget};
+
+impl Window {
+    pub(super) fn text_transform(&self) -> widget::Column<'_, Message> {
+        let spacing = cosmic::theme::active().cosmic().space_xxs();
+        let transformed = self.transform.apply(&self.transform_text);
+        let transformed = Some(transformed).filter(|_| !self.transform_text.trim().is_empty());
+
+        let mut text_transform = widget::column::with_capacity(Transform::ALL.len() + 4)
+            .spacing(spacing)
+            .push(page_header(fl!("text-transform")));
+
+        let mut text_input = widget::text_input(fl!("text-to-transform"), &self.transform_text)
+            .on_input(Message::TransformText)
+            .on_paste(Message::TransformText);
+        if let Some(transformed) = &transformed {
+            text_input = text_input.on_submit(Message::EmojiCopy(
+                Copyable::Text(transformed.clone()),
+                self.pick_click_mode(),
+            ));
+        }
+        text_transform = text_transform.push(text_input);
+
+        for transform in Transform::ALL {
+            let label = match transform {
+                Transform::RegionalIndicators => fl!("transform-regional-indicators"),
+                Transform::Squared => fl!("transform-squared"),
+                Transform::NegativeSquared => fl!("transform-negative-squared"),
+                Transform::Keycaps => fl!("transform-keycaps"),
+                Transform::Clap => fl!("transform-clap"),
+            };
+            text_transform = text_transform.push(widget::radio(
+                label,
+                transform,
+                Some(self.transform),
+                Message::Transform,
+            ));
+        }
+
+        let preview = widget::text(transformed.clone().unwrap_or_default())
+            .size(25)
+            .font(self.font_family)
+            .shaping(iced_core::text::Shaping::Advanced)
+            .wrap(iced::widget::text::Wrap::Word)
+            .vertical_alignment(alignment::Vertical::Center);
+        text_transform = text_transform.push(preview);
+
+        let buttons = widget::row::with_capacity(2)
+            .spacing(spacing)
+            .push(
+                widget::button::standard(fl!("add-to-compose")).on_press_maybe(
+                    transformed.clone().map(|transformed| {
+                        Message::EmojiCopy(Copyable::Text(transformed), ClickMode::COMPOSE)
+                    }),
+                ),
+            )
+            .push(
+                widget::button::suggested(fl!("copy")).on_press_maybe(transformed.map(
+                    |transformed| {
+                        Message::EmojiCopy(Copyable::Text(transformed), self.pick_click_mode())
+                    },
+                )),
+            );
+        text_transform.push(buttons)
+    }
+}
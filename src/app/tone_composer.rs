@@ -0,0 +1,66 @@
+use super::{page_header, style, Copyable, Message, Window};
+use crate::config::{rgba, SKIN_TONE_COLORS};
+use crate::{fl, utils};
+
+use cosmic::iced::{alignment, Alignment, Length};
+use cosmic::{iced, iced_core, widget};
+
+impl Window {
+    pub(super) fn tone_composer(
+        &self,
+        emoji: &'static emojis::Emoji,
+    ) -> widget::Column<'_, Message> {
+        let spacing = cosmic::theme::active().cosmic().space_xxs();
+        let [first, second] = self.composer_skin_tones;
+        let composed = utils::compose_skin_tone(first, second)
+            .and_then(|skin_tone| emoji.with_skin_tone(skin_tone));
+
+        let mut tone_composer = widget::column::with_capacity(5)
+            .spacing(spacing)
+            .push(page_header(fl!("compose-skin-tones")));
+
+        let preview: cosmic::Element<'_, Message> = match composed {
+            Some(composed) => widget::text(composed.as_str())
+                .size(50)
+                .height(70)
+                .font(self.font_family)
+                .shaping(iced_core::text::Shaping::Advanced)
+                .wrap(iced::widget::text::Wrap::None)
+                .vertical_alignment(alignment::Vertical::Center)
+                .into(),
+            None => widget::text::body(fl!("no-such-variant"))
+                .height(70)
+                .vertical_alignment(alignment::Vertical::Center)
+                .into(),
+        };
+        tone_composer = tone_composer.push(preview);
+
+        for (person, selected) in self.composer_skin_tones.into_iter().enumerate() {
+            let mut color_buttons = widget::row::with_capacity(7)
+                .spacing(spacing)
+                .align_items(Alignment::Center)
+                .push(widget::text::body(fl!("person", number = person + 1)))
+                .push(widget::horizontal_space(Length::Fill));
+            for (skin_tone, color) in utils::PERSON_SKIN_TONES.into_iter().zip(SKIN_TONE_COLORS) {
+                color_buttons = color_buttons.push(
+                    widget::button(widget::horizontal_space(0.1))
+                        .width(20)
+                        .height(20)
+                        .style(style::color_button_style(
+                            rgba(color),
+                            skin_tone == selected,
+                        ))
+                        .on_press(Message::ComposeSkinTone(person, skin_tone)),
+                );
+            }
+            tone_composer = tone_composer.push(color_buttons);
+        }
+
+        let copy_btn =
+            widget::button::suggested(fl!("copy")).on_press_maybe(composed.map(|composed| {
+                Message::EmojiCopy(Copyable::Emoji(composed), self.pick_click_mode())
+            }));
+        tone_composer = tone_composer.push(copy_btn);
+        tone_composer
+    }
+}
@@ -0,0 +1,104 @@
+use super::{group_string, page_header, Message, Window};
+use crate::{fl, stats};
+
+use cosmic::iced::{alignment, Alignment, Length};
+use cosmic::{iced, iced_core, widget, Apply};
+
+impl Window {
+    pub(super) fn usage(&self) -> widget::Column<'_, Message> {
+        let spacing = cosmic::theme::active().cosmic().space_xxs();
+        let usage_stats = &self.usage_stats;
+        let today = stats::today();
+
+        let mut sections = widget::column::with_capacity(5).spacing(spacing);
+
+        sections = sections.push(widget::text::heading(fl!("most-used-week")));
+        sections = sections.push(self.top_emojis(stats::top_emojis(usage_stats, today, 7, 10)));
+        sections = sections.push(widget::text::heading(fl!("most-used-month")));
+        sections = sections.push(self.top_emojis(stats::top_emojis(usage_stats, today, 30, 10)));
+
+        sections = sections.push(widget::text::heading(fl!("usage-by-group")));
+        for (group, count) in stats::group_breakdown(usage_stats) {
+            let row = widget::row::with_capacity(3)
+                .push(widget::text::body(group_string(group)))
+                .push(widget::horizontal_space(Length::Fill))
+                .push(widget::text::body(count.to_string()));
+            sections = sections.push(row);
+        }
+
+        let (current_streak, longest_streak) = stats::streaks(usage_stats, today);
+        sections = sections.push(widget::text::heading(fl!("streaks")));
+        sections = sections.push(widget::text::body(fl!(
+            "current-streak",
+            days = current_streak
+        )));
+        sections = sections.push(widget::text::body(fl!(
+            "longest-streak",
+            days = longest_streak
+        )));
+
+        let sections = widget::scrollable(sections)
+            .height(Length::Fill)
+            .width(Length::Fill)
+            .apply(widget::container)
+            .height(400);
+
+        let export_btn = widget::button::standard(fl!("export-csv"))
+            .on_press_maybe((!usage_stats.is_empty()).then_some(Message::ExportStats));
+        let mut export_row = widget::row::with_capacity(2)
+            .spacing(spacing)
+            .align_items(Alignment::Center)
+            .push(export_btn);
+        match &self.stats_export {
+            Some(Ok(path)) => {
+                let path = path.display().to_string();
+                export_row =
+                    export_row.push(widget::text::caption(fl!("exported-to", path = path)));
+            }
+            Some(Err(err)) => {
+                export_row = export_row.push(widget::text::caption(fl!(
+                    "export-failed",
+                    error = err.as_str()
+                )));
+            }
+            None => {}
+        }
+
+        widget::column::with_capacity(4)
+            .spacing(spacing)
+            .push(page_header(fl!("usage-statistics")))
+            .push(widget::text::caption(fl!("usage-statistics-local")))
+            .push(sections)
+            .push(export_row)
+    }
+
+    fn top_emojis(
+        &self,
+        top_emojis: Vec<(&'static emojis::Emoji, u32)>,
+    ) -> widget::FlexRow<'static, Message> {
+        let mut top_emojis_view = Vec::with_capacity(top_emojis.len());
+        for (emoji, count) in top_emojis {
+            let emoji_txt = widget::text(emoji.as_str())
+                .size(25)
+                .width(35)
+                .height(35)
+                .font(self.font_family)
+                .shaping(iced_core::text::Shaping::Advanced)
+                .wrap(iced::widget::text::Wrap::None)
+                .horizontal_alignment(alignment::Horizontal::Center)
+                .vertical_alignment(alignment::Vertical::Center);
+            let entry = widget::column::with_capacity(2)
+                .align_items(Alignment::Center)
+                .push(emoji_txt)
+                .push(widget::text::caption(count.to_string()));
+            top_emojis_view.push(entry.into());
+        }
+        if top_emojis_view.is_empty() {
+            top_emojis_view.push(widget::text::caption(fl!("no-usage")).into());
+        }
+        widget::flex_row(top_emojis_view)
+            .row_spacing(0)
+            .column_spacing(0)
+            .width(Length::Fill)
+    }
+}
@@ -0,0 +1,63 @@
+use super::{Message, Window};
+use crate::{fl, utils};
+
+use cosmic::iced::{alignment, Alignment, Length};
+use cosmic::{iced, iced_core, widget, Apply, Element};
+
+impl Window {
+    pub(super) fn variants_popup(
+        &self,
+        emoji: &'static emojis::Emoji,
+    ) -> Element<'static, Message> {
+        let spacing = cosmic::theme::active().cosmic().space_xxs();
+        let mut variants_view = Vec::new();
+        for variant in emoji.skin_tones().into_iter().flatten() {
+            let variant_txt = widget::text(variant.as_str())
+                .size(25)
+                .width(35)
+                .height(35)
+                .font(self.font_family)
+                .shaping(iced_core::text::Shaping::Advanced)
+                .wrap(iced::widget::text::Wrap::None)
+                .horizontal_alignment(alignment::Horizontal::Center)
+                .vertical_alignment(alignment::Vertical::Center);
+            let variant_btn = widget::button(variant_txt)
+                .style(cosmic::theme::Button::Transparent)
+                .on_press(Message::VariantPicked(variant));
+            variants_view.push(variant_btn.into());
+        }
+        let variants = widget::flex_row(variants_view)
+            .row_spacing(0)
+            .column_spacing(0)
+            // room for the default and the five skin tones in one row
+            .width(Length::Fixed(306.0));
+
+        let remember = widget::row::with_capacity(2)
+            .spacing(spacing)
+            .align_items(Alignment::Center)
+            .push(widget::text::caption(fl!("remember-skin-tone")))
+            .push(widget::horizontal_space(Length::Fill))
+            .push(widget::toggler(
+                None,
+                self.config.remember_skin_tone,
+                Message::RememberSkinTone,
+            ));
+
+        let mut popup = widget::column::with_capacity(3)
+            .spacing(spacing)
+            .push(variants)
+            .push(remember);
+        if utils::is_multi_person(emoji) {
+            popup = popup.push(
+                widget::button::standard(fl!("compose-skin-tones"))
+                    .on_press(Message::OpenToneComposer(emoji)),
+            );
+        }
+        popup
+            .apply(widget::container)
+            .padding(spacing)
+            .width(Length::Shrink)
+            .style(cosmic::theme::Container::Dropdown)
+            .into()
+    }
+}
@@ -0,0 +1,90 @@
+use super::{Copyable, Message, Window};
+use crate::{fl, utils};
+
+use cosmic::iced::{alignment, Alignment, Length};
+use cosmic::{iced, iced_core, widget, Element};
+
+impl Window {
+    pub(super) fn zwj_builder_bar(
+        &self,
+        components: &[&'static emojis::Emoji],
+    ) -> widget::Column<'_, Message> {
+        let spacing = cosmic::theme::active().cosmic().space_xxs();
+        let zwj_emoji = utils::zwj_emoji(components);
+
+        let mut sequence = widget::row::with_capacity(components.len() * 2 + 1)
+            .spacing(spacing)
+            .align_items(Alignment::Center);
+        for (idx, component) in components.iter().enumerate() {
+            if idx > 0 {
+                sequence = sequence.push(widget::text::body("+"));
+            }
+            sequence = sequence.push(self.zwj_component_txt(component.as_str(), 25));
+        }
+        if let Some(zwj_emoji) = zwj_emoji {
+            sequence = sequence
+                .push(widget::text::body("="))
+                .push(self.zwj_component_txt(zwj_emoji.as_str(), 25));
+        }
+
+        let bar = widget::row::with_capacity(5)
+            .spacing(spacing)
+            .align_items(Alignment::Center)
+            .push(sequence)
+            .push(widget::horizontal_space(Length::Fill))
+            .push(
+                widget::button::icon(widget::icon::from_name("edit-undo-symbolic").symbolic(true))
+                    .on_press_maybe((!components.is_empty()).then_some(Message::ZwjPop)),
+            )
+            .push(
+                widget::button::suggested(fl!("copy"))
+                    .on_press_maybe(zwj_emoji.map(|_| Message::ZwjCopy)),
+            )
+            .push(
+                widget::button::icon(
+                    widget::icon::from_name("window-close-symbolic").symbolic(true),
+                )
+                .on_press(Message::CloseZwjBuilder),
+            );
+
+        let hint = match (self.zwj_rejected, components.is_empty(), zwj_emoji) {
+            (Some(rejected), _, _) => fl!("zwj-cannot-combine", emoji = rejected.as_str()),
+            (None, true, _) => fl!("zwj-pick-component"),
+            (None, false, None) => fl!("zwj-incomplete"),
+            (None, false, Some(_)) => fl!("zwj-complete"),
+        };
+
+        widget::column::with_capacity(2)
+            .push(bar)
+            .push(widget::text::caption(hint))
+    }
+
+    /// The components of a ZWJ sequence, each picked like an emoji from the grid.
+    pub(super) fn zwj_decomposition(
+        &self,
+        components: Vec<&'static emojis::Emoji>,
+    ) -> widget::Row<'static, Message> {
+        let click_mode = self.pick_click_mode();
+        let mut decomposition = widget::row::with_capacity(components.len());
+        for component in components {
+            decomposition = decomposition.push(
+                widget::button(self.zwj_component_txt(component.as_str(), 14))
+                    .padding(0)
+                    .style(cosmic::theme::Button::Transparent)
+                    .on_press(Message::EmojiCopy(Copyable::Emoji(component), click_mode)),
+            );
+        }
+        decomposition
+    }
+
+    fn zwj_component_txt(&self, emoji: &'static str, size: u16) -> Element<'static, Message> {
+        widget::text(emoji)
+            .size(size)
+            .font(self.font_family)
+            .shaping(iced_core::text::Shaping::Advanced)
+            .wrap(iced::widget::text::Wrap::None)
+            .horizontal_alignment(alignment::Horizontal::Center)
+            .vertical_alignment(alignment::Vertical::Center)
+            .into()
+    }
+}
@@ -1,11 +1,25 @@
 // SPDX-License-Identifier: MPL-2.0
 // 2024 - Dominic Gerhauser and contributors
+use crate::stats::UsageDay;
 use bitflags::bitflags;
 use cosmic::cosmic_config::{self, cosmic_config_derive::CosmicConfigEntry, CosmicConfigEntry};
 use serde::{Deserialize, Serialize};
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 pub const CONFIG_VERSION: u64 = 1;
 
+/// Colors of the default, light, medium-light, medium, medium-dark and dark skin tones.
+pub const SKIN_TONE_COLORS: [u32; 6] = [
+    0xFFCC22_ffu32,
+    0xf7dece_ff,
+    0xf3d2a2_ff,
+    0xbf8d67_ff,
+    0xaf7e57_ff,
+    0x7c533e_ff,
+];
+
+/// Collections which can be created, one for each of the keys `a` to `z`.
+pub const MAX_COLLECTIONS: usize = 26;
+
 #[derive(Clone, CosmicConfigEntry, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Config {
     #[serde(default)]
@@ -26,26 +40,47 @@ pub struct Config {
     pub show_preview: bool,
     #[serde(default)]
     pub skin_tone_mode: SkinToneMode,
+    /// `None` for configs from before the filter, which is then derived from `skin_tone_mode`.
+    #[serde(default)]
+    pub skin_tone_filter: Option<SkinToneFilter>,
     #[serde(default)]
     pub emoji_ordering: EmojiOrdering,
+    #[serde(default)]
+    pub collections: Vec<Collection>,
+    #[serde(default)]
+    pub snippets: Vec<Snippet>,
+    /// Usage data of older versions, moved to [`crate::stats::data_dir`] on the first start.
+    #[serde(default)]
+    pub usage_stats: Vec<UsageDay>,
+    #[serde(default)]
+    pub remember_skin_tone: bool,
+    #[serde(default)]
+    pub preferred_skin_tones: HashMap<&'static emojis::Emoji, &'static emojis::Emoji>,
+    /// Milliseconds until the clipboard is restored with `ClickMode::RESTORE_CLIPBOARD`.
+    #[serde(default)]
+    pub restore_clipboard_timeout: u64,
+    #[serde(default)]
+    pub gender_mode: GenderMode,
+    #[serde(default)]
+    pub hair_style_mode: HairStyleMode,
+    /// Width and height in pixels of emojis copied or saved as images.
+    #[serde(default)]
+    pub image_size: u32,
+    #[serde(default)]
+    pub presentation: Presentation,
+    /// The key chord sent with `ClickMode::AUTO_PASTE`, e.g. `Ctrl+Shift+V` for terminals.
+    #[serde(default)]
+    pub paste_chord: String,
 }
 
 impl Default for Config {
     fn default() -> Self {
-        let colors = [
-            0xFFCC22_ffu32,
-            0xf7dece_ff,
-            0xf3d2a2_ff,
-            0xbf8d67_ff,
-            0xaf7e57_ff,
-            0x7c533e_ff,
-        ];
         let color_buttons = SkinToneMode::ALL
             .iter()
             .take(6)
-            .zip(colors)
+            .zip(SKIN_TONE_COLORS)
             .map(|(skin_tone, color)| ColorButton {
-                color: color.to_be_bytes().map(|c| c as f32 / 255.),
+                color: rgba(color),
                 skin_tone_mode: skin_tone,
                 active: skin_tone == SkinToneMode::DEFAULT,
             })
@@ -60,10 +95,56 @@ impl Default for Config {
             right_click_action: ClickMode::APPEND_SEARCH | ClickMode::COPY,
             middle_click_action: ClickMode::COPY,
             skin_tone_mode: SkinToneMode::DEFAULT | SkinToneMode::NO_SKIN,
+            skin_tone_filter: None,
             emoji_ordering: EmojiOrdering::default(),
+            collections: Vec::new(),
+            snippets: Vec::new(),
+            usage_stats: Vec::new(),
+            remember_skin_tone: false,
+            preferred_skin_tones: HashMap::new(),
+            restore_clipboard_timeout: 5000,
+            gender_mode: GenderMode::ALL,
+            hair_style_mode: HairStyleMode::ALL,
+            image_size: 128,
+            presentation: Presentation::AsListed,
+            paste_chord: "Ctrl+V".into(),
         }
     }
 }
+
+/// A config handler in a temporary `XDG_CONFIG_HOME`, which is shared by all tests.
+#[cfg(test)]
+pub(crate) fn test_config_handler(name: &str) -> cosmic_config::Config {
+    static CONFIG_HOME: once_cell::sync::Lazy<tempfile::TempDir> =
+        once_cell::sync::Lazy::new(|| {
+            let config_home = tempfile::tempdir().unwrap();
+            std::env::set_var("XDG_CONFIG_HOME", config_home.path());
+            config_home
+        });
+    once_cell::sync::Lazy::force(&CONFIG_HOME);
+    cosmic_config::Config::new(&format!("{}.{}", crate::app::ID, name), CONFIG_VERSION).unwrap()
+}
+
+impl Config {
+    pub fn active_skin_tone_filter(&self) -> SkinToneFilter {
+        self.skin_tone_filter
+            .unwrap_or_else(|| SkinToneFilter::from(self.skin_tone_mode))
+    }
+
+    /// Whether the skin tones of the color buttons are shown,
+    /// otherwise the buttons are inactive until one is pressed.
+    pub fn color_buttons_apply(&self) -> bool {
+        matches!(
+            self.active_skin_tone_filter(),
+            SkinToneFilter::Chosen { .. }
+        )
+    }
+}
+
+pub fn rgba(color: u32) -> [f32; 4] {
+    color.to_be_bytes().map(|c| c as f32 / 255.)
+}
+
 #[derive(Copy, Serialize, Deserialize, PartialEq, Hash, Debug, Default, Clone)]
 pub enum EmojiOrdering {
     #[default]
@@ -71,6 +152,20 @@ pub enum EmojiOrdering {
     Unicode,
 }
 
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Collection {
+    pub name: String,
+    pub icon: &'static emojis::Emoji,
+    #[serde(default)]
+    pub emojis: Vec<&'static emojis::Emoji>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Snippet {
+    pub name: String,
+    pub text: String,
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Hash, Debug, Default, Clone)]
 pub struct Annotation {
     #[serde(default)]
@@ -80,13 +175,32 @@ pub struct Annotation {
 }
 bitflags! {
     #[derive(Serialize, Deserialize, PartialEq, Hash, Debug, Default, Copy, Clone, Eq)]
-    pub struct ClickMode: u8 {
+    pub struct ClickMode: u32 {
         const NONE = 0;
         const COPY = 1;
         const CLOSE = 1 << 1;
         const APPEND_SEARCH = 1 << 2;
         const PRIVATE = 1 << 3;
         const CLEAR_SEARCH = 1 << 4;
+        const CONTEXT_MENU = 1 << 5;
+        const VARIANTS = 1 << 6;
+        const TYPE = 1 << 7;
+        const RESTORE_CLIPBOARD = 1 << 8;
+        const AS_SHORTCODE = 1 << 9;
+        const AS_CODEPOINT = 1 << 10;
+        const AS_HTML_ENTITY = 1 << 11;
+        const AS_RUST = 1 << 12;
+        const AS_JSON = 1 << 13;
+        const AS_PYTHON = 1 << 14;
+        const AS_URL_ENCODED = 1 << 15;
+        const AS_NAME = 1 << 16;
+        const PRIMARY = 1 << 17;
+        const COMPOSE = 1 << 18;
+        const EMOJI_PRESENTATION = 1 << 19;
+        const TEXT_PRESENTATION = 1 << 20;
+        const MINIMAL_PRESENTATION = 1 << 21;
+        const AUTO_PASTE = 1 << 22;
+        const HTML = 1 << 23;
     }
 
 }
@@ -137,6 +251,11 @@ bitflags! {
 }
 
 impl SkinToneMode {
+    /// The two most-significant bits are reserved and must not be set.
+    pub fn has_reserved_bits(self) -> bool {
+        !Self::all().contains(self)
+    }
+
     pub fn new(skin_tone: emojis::SkinTone) -> Self {
         match skin_tone {
             emojis::SkinTone::Default => SkinToneMode::DEFAULT,
@@ -221,10 +340,433 @@ impl SkinToneMode {
         }
     }
 }
-#[derive(Serialize, Deserialize, PartialEq, Debug, Default, Copy, Clone)]
+/// Which skin tone variants are shown in the grid.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Copy, Clone)]
+pub enum SkinToneFilter {
+    /// Emojis in their default skin tone and emojis without a skin tone.
+    DefaultOnly,
+    /// Emojis in the chosen skin tones, usually picked with the color buttons.
+    Chosen {
+        tones: SkinToneMode,
+        matching: SkinToneMatching,
+    },
+    /// One tile per emoji, its skin tones are grouped under it.
+    Grouped,
+}
+
+/// Which variation selectors copied emojis get, e.g. `U+FE0F` for ❤️ or `U+FE0E` for ❤︎.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Default, Copy, Clone)]
+pub enum Presentation {
+    /// As in the emoji list, which is fully qualified.
+    #[default]
+    AsListed,
+    /// Adds `U+FE0F` to characters which are shown as text by default.
+    Emoji,
+    /// Adds `U+FE0E` to characters which are shown as text by default, unless part of a sequence.
+    Text,
+    /// Only keeps the variation selector of the first character of each sequence.
+    Minimal,
+}
+
+impl Presentation {
+    pub const ALL: [Presentation; 4] = [
+        Presentation::AsListed,
+        Presentation::Emoji,
+        Presentation::Text,
+        Presentation::Minimal,
+    ];
+
+    /// The bit of a click mode which overrides the presentation for one copy.
+    pub fn click_mode(self) -> ClickMode {
+        match self {
+            Presentation::AsListed => ClickMode::NONE,
+            Presentation::Emoji => ClickMode::EMOJI_PRESENTATION,
+            Presentation::Text => ClickMode::TEXT_PRESENTATION,
+            Presentation::Minimal => ClickMode::MINIMAL_PRESENTATION,
+        }
+    }
+
+    /// The first presentation set in `click_mode`, if any.
+    pub fn from_click_mode(click_mode: ClickMode) -> Option<Presentation> {
+        Presentation::ALL
+            .into_iter()
+            .skip(1)
+            .find(|presentation| click_mode.intersects(presentation.click_mode()))
+    }
+}
+
+/// How the skin tones of an emoji are matched against the chosen skin tones.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Copy, Clone)]
+pub enum SkinToneMatching {
+    /// Every person has one of the chosen skin tones.
+    EveryPerson,
+    /// At least one person has one of the chosen skin tones.
+    AnyPerson,
+    /// The skin tones of all persons were chosen as one, e.g. `LIGHT_AND_DARK`.
+    Exact,
+}
+
+impl SkinToneFilter {
+    /// Filters by the chosen skin tones, with the matching selected by the filter bits.
+    pub fn chosen(skin_tone_mode: SkinToneMode) -> Self {
+        let matching = if skin_tone_mode.intersects(SkinToneMode::ALL_EXACT) {
+            SkinToneMatching::Exact
+        } else if skin_tone_mode.intersects(SkinToneMode::FILTER_INTERSECT) {
+            SkinToneMatching::AnyPerson
+        } else {
+            SkinToneMatching::EveryPerson
+        };
+        let tones = skin_tone_mode
+            .difference(SkinToneMode::FILTER_EXACT | SkinToneMode::FILTER_INTERSECT)
+            .intersection(SkinToneMode::all());
+        SkinToneFilter::Chosen { tones, matching }
+    }
 
+    /// The filter after the color buttons chose `skin_tone_mode`, keeping the matching of a
+    /// chosen filter. Exact skin tones like `LIGHT_AND_DARK` only ever match exactly.
+    pub fn with_chosen_tones(self, skin_tone_mode: SkinToneMode) -> Self {
+        match (self, SkinToneFilter::chosen(skin_tone_mode)) {
+            (SkinToneFilter::Chosen { matching, .. }, SkinToneFilter::Chosen { tones, .. })
+                if !tones.intersects(SkinToneMode::ALL_EXACT) =>
+            {
+                SkinToneFilter::Chosen { tones, matching }
+            }
+            (_, chosen) => chosen,
+        }
+    }
+
+    pub fn accepts(&self, skin_tone: Option<emojis::SkinTone>) -> bool {
+        match (self, skin_tone) {
+            (SkinToneFilter::DefaultOnly | SkinToneFilter::Grouped, None) => true,
+            (SkinToneFilter::DefaultOnly | SkinToneFilter::Grouped, Some(skin_tone)) => {
+                skin_tone == emojis::SkinTone::Default
+            }
+            (SkinToneFilter::Chosen { tones, .. }, None) => tones.contains(SkinToneMode::NO_SKIN),
+            (SkinToneFilter::Chosen { tones, matching }, Some(skin_tone)) => match matching {
+                SkinToneMatching::EveryPerson => tones.contains(SkinToneMode::new(skin_tone)),
+                SkinToneMatching::AnyPerson => tones.intersects(SkinToneMode::new(skin_tone)),
+                SkinToneMatching::Exact => tones.intersects(SkinToneMode::new_exact(skin_tone)),
+            },
+        }
+    }
+}
+
+/// Migrates the skin tone bits used before [`SkinToneFilter`].
+impl From<SkinToneMode> for SkinToneFilter {
+    fn from(skin_tone_mode: SkinToneMode) -> Self {
+        if skin_tone_mode == SkinToneMode::DEFAULT | SkinToneMode::NO_SKIN {
+            SkinToneFilter::DefaultOnly
+        } else {
+            SkinToneFilter::chosen(skin_tone_mode)
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Default, Copy, Clone)]
 pub struct ColorButton {
     pub color: [f32; 4],
     pub skin_tone_mode: SkinToneMode,
     pub active: bool,
 }
+
+#[derive(Serialize, Deserialize, PartialEq, Hash, Debug, Default, Copy, Clone, Eq)]
+#[serde(transparent)]
+pub struct GenderMode(u32);
+bitflags! {
+    impl GenderMode: u32 {
+        const PERSON = 1;
+        const MAN = 1 << 1;
+        const WOMAN = 1 << 2;
+        const NO_GENDER = 1 << 3;
+
+        const ALL = (1 << 4) - 1;
+    }
+}
+
+impl GenderMode {
+    pub fn new(emoji: &emojis::Emoji) -> Self {
+        const MAN: &[char] = &[
+            '\u{2642}',
+            '\u{1f468}',
+            '\u{1f466}',
+            '\u{1f474}',
+            '\u{1f934}',
+            '\u{1f57a}',
+            '\u{1fac3}',
+        ];
+        const WOMAN: &[char] = &[
+            '\u{2640}',
+            '\u{1f469}',
+            '\u{1f467}',
+            '\u{1f475}',
+            '\u{1f478}',
+            '\u{1f483}',
+            '\u{1f930}',
+        ];
+        const PERSON: &[char] = &[
+            '\u{1f9d1}',
+            '\u{1f9d2}',
+            '\u{1f9d3}',
+            '\u{1fac4}',
+            '\u{1fac5}',
+        ];
+
+        let emoji_str = emoji.as_str();
+        match (emoji_str.contains(MAN), emoji_str.contains(WOMAN)) {
+            (true, false) => return GenderMode::MAN,
+            (false, true) => return GenderMode::WOMAN,
+            // e.g. families and couples
+            (true, true) => return GenderMode::NO_GENDER,
+            (false, false) => {}
+        }
+        if emoji_str.contains(PERSON) {
+            return GenderMode::PERSON;
+        }
+        // gender neutral emojis like 🏃 have a man and a woman version
+        let base = emoji_str.replace(|c| matches!(c, '\u{1f3fb}'..='\u{1f3ff}' | '\u{fe0f}'), "");
+        if emojis::get(&format!("{base}\u{200d}\u{2640}\u{fe0f}")).is_some() {
+            return GenderMode::PERSON;
+        }
+        GenderMode::NO_GENDER
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Hash, Debug, Default, Copy, Clone, Eq)]
+#[serde(transparent)]
+pub struct HairStyleMode(u32);
+bitflags! {
+    impl HairStyleMode: u32 {
+        const RED = 1;
+        const CURLY = 1 << 1;
+        const WHITE = 1 << 2;
+        const BALD = 1 << 3;
+        const NO_HAIR_STYLE = 1 << 4;
+
+        const ALL = (1 << 5) - 1;
+    }
+}
+
+impl HairStyleMode {
+    pub fn new(emoji: &emojis::Emoji) -> Self {
+        let emoji_str = emoji.as_str();
+        if emoji_str.contains('\u{1f9b0}') {
+            HairStyleMode::RED
+        } else if emoji_str.contains('\u{1f9b1}') {
+            HairStyleMode::CURLY
+        } else if emoji_str.contains('\u{1f9b3}') {
+            HairStyleMode::WHITE
+        } else if emoji_str.contains('\u{1f9b2}') {
+            HairStyleMode::BALD
+        } else {
+            HairStyleMode::NO_HAIR_STYLE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmic::cosmic_config::ConfigSet;
+
+    /// Every skin tone, from the emoji with the most skin tone variants.
+    fn all_skin_tones() -> Vec<emojis::SkinTone> {
+        let people_holding_hands = emojis::get("🧑‍🤝‍🧑").unwrap();
+        people_holding_hands
+            .skin_tones()
+            .unwrap()
+            .map(|emoji| emoji.skin_tone().unwrap())
+            .collect()
+    }
+
+    fn accepted(filter: SkinToneFilter) -> Vec<emojis::SkinTone> {
+        all_skin_tones()
+            .into_iter()
+            .filter(|&skin_tone| filter.accepts(Some(skin_tone)))
+            .collect()
+    }
+
+    #[test]
+    fn every_skin_tone_has_a_mode() {
+        let skin_tones = all_skin_tones();
+        assert_eq!(skin_tones.len(), 26);
+        for skin_tone in skin_tones {
+            let mode = SkinToneMode::new(skin_tone);
+            let mode_exact = SkinToneMode::new_exact(skin_tone);
+            assert_ne!(mode, SkinToneMode::OTHER, "{skin_tone:?}");
+            assert_ne!(mode_exact, SkinToneMode::OTHER, "{skin_tone:?}");
+            assert_eq!(mode_exact.bits().count_ones(), 1, "{skin_tone:?}");
+            assert!(!mode.has_reserved_bits() && !mode_exact.has_reserved_bits());
+        }
+    }
+
+    #[test]
+    fn default_only_and_grouped() {
+        for filter in [SkinToneFilter::DefaultOnly, SkinToneFilter::Grouped] {
+            assert!(filter.accepts(None));
+            assert_eq!(accepted(filter), [emojis::SkinTone::Default]);
+        }
+    }
+
+    #[test]
+    fn chosen_every_person() {
+        use emojis::SkinTone::*;
+        let filter = SkinToneFilter::Chosen {
+            tones: SkinToneMode::LIGHT | SkinToneMode::DARK,
+            matching: SkinToneMatching::EveryPerson,
+        };
+        assert!(!filter.accepts(None));
+        assert_eq!(accepted(filter), [Light, Dark, LightAndDark, DarkAndLight]);
+    }
+
+    #[test]
+    fn chosen_any_person() {
+        use emojis::SkinTone::*;
+        let filter = SkinToneFilter::Chosen {
+            tones: SkinToneMode::LIGHT | SkinToneMode::NO_SKIN,
+            matching: SkinToneMatching::AnyPerson,
+        };
+        assert!(filter.accepts(None));
+        assert_eq!(
+            accepted(filter),
+            [
+                Light,
+                LightAndMediumLight,
+                LightAndMedium,
+                LightAndMediumDark,
+                LightAndDark,
+                MediumLightAndLight,
+                MediumAndLight,
+                MediumDarkAndLight,
+                DarkAndLight,
+            ]
+        );
+    }
+
+    #[test]
+    fn chosen_exact() {
+        use emojis::SkinTone::*;
+        let filter = SkinToneFilter::Chosen {
+            tones: SkinToneMode::MEDIUM | SkinToneMode::LIGHT_AND_DARK,
+            matching: SkinToneMatching::Exact,
+        };
+        assert_eq!(accepted(filter), [Medium, LightAndDark]);
+    }
+
+    #[test]
+    fn chosen_by_every_single_mode() {
+        for skin_tone in all_skin_tones() {
+            let filter = SkinToneFilter::chosen(SkinToneMode::new_exact(skin_tone));
+            assert_eq!(accepted(filter), [skin_tone]);
+        }
+    }
+
+    #[test]
+    fn choose_tones_with_color_buttons() {
+        let any_person = SkinToneFilter::Chosen {
+            tones: SkinToneMode::LIGHT,
+            matching: SkinToneMatching::AnyPerson,
+        };
+        assert_eq!(
+            any_person.with_chosen_tones(SkinToneMode::LIGHT | SkinToneMode::DARK),
+            SkinToneFilter::Chosen {
+                tones: SkinToneMode::LIGHT | SkinToneMode::DARK,
+                matching: SkinToneMatching::AnyPerson,
+            }
+        );
+        // exact skin tones never match otherwise
+        let exact =
+            any_person.with_chosen_tones(SkinToneMode::LIGHT | SkinToneMode::LIGHT_AND_DARK);
+        assert_eq!(
+            exact,
+            SkinToneFilter::Chosen {
+                tones: SkinToneMode::LIGHT | SkinToneMode::LIGHT_AND_DARK,
+                matching: SkinToneMatching::Exact,
+            }
+        );
+        assert_eq!(
+            accepted(exact),
+            [emojis::SkinTone::Light, emojis::SkinTone::LightAndDark]
+        );
+        assert_eq!(
+            SkinToneFilter::Grouped.with_chosen_tones(SkinToneMode::DARK),
+            SkinToneFilter::Chosen {
+                tones: SkinToneMode::DARK,
+                matching: SkinToneMatching::EveryPerson,
+            }
+        );
+    }
+
+    #[test]
+    fn migrate_skin_tone_mode() {
+        assert_eq!(
+            SkinToneFilter::from(Config::default().skin_tone_mode),
+            SkinToneFilter::DefaultOnly
+        );
+        assert_eq!(
+            SkinToneFilter::from(SkinToneMode::LIGHT | SkinToneMode::FILTER_INTERSECT),
+            SkinToneFilter::Chosen {
+                tones: SkinToneMode::LIGHT,
+                matching: SkinToneMatching::AnyPerson,
+            }
+        );
+        assert_eq!(
+            SkinToneFilter::from(SkinToneMode::LIGHT | SkinToneMode::FILTER_EXACT),
+            SkinToneFilter::Chosen {
+                tones: SkinToneMode::LIGHT,
+                matching: SkinToneMatching::Exact,
+            }
+        );
+        // reserved bits are dropped
+        assert_eq!(
+            SkinToneFilter::from(SkinToneMode::from_bits_retain(1 << 31 | 1)),
+            SkinToneFilter::Chosen {
+                tones: SkinToneMode::DEFAULT,
+                matching: SkinToneMatching::EveryPerson,
+            }
+        );
+        assert_eq!(
+            Config::default().active_skin_tone_filter(),
+            SkinToneFilter::DefaultOnly
+        );
+
+        // configs from before the filter do not have the key
+        let config_handler = test_config_handler("MigrateSkinToneMode");
+        config_handler
+            .set("skin_tone_mode", SkinToneMode::ALL)
+            .unwrap();
+        let legacy = Config::get_entry(&config_handler).unwrap_or_else(|(_, config)| config);
+        assert_eq!(legacy.skin_tone_filter, None);
+        assert_eq!(
+            legacy.active_skin_tone_filter(),
+            SkinToneFilter::Chosen {
+                tones: SkinToneMode::ALL,
+                matching: SkinToneMatching::Exact,
+            }
+        );
+    }
+
+    #[test]
+    fn gender_and_hair_style() {
+        let table = [
+            ("🏃", GenderMode::PERSON, HairStyleMode::NO_HAIR_STYLE),
+            ("🏃🏽", GenderMode::PERSON, HairStyleMode::NO_HAIR_STYLE),
+            ("🏃‍♀️", GenderMode::WOMAN, HairStyleMode::NO_HAIR_STYLE),
+            ("👮", GenderMode::PERSON, HairStyleMode::NO_HAIR_STYLE),
+            ("👨‍⚕️", GenderMode::MAN, HairStyleMode::NO_HAIR_STYLE),
+            ("🧑‍⚕️", GenderMode::PERSON, HairStyleMode::NO_HAIR_STYLE),
+            ("👩‍🦰", GenderMode::WOMAN, HairStyleMode::RED),
+            ("🧑‍🦲", GenderMode::PERSON, HairStyleMode::BALD),
+            ("👪", GenderMode::NO_GENDER, HairStyleMode::NO_HAIR_STYLE),
+            ("👨‍👩‍👧", GenderMode::NO_GENDER, HairStyleMode::NO_HAIR_STYLE),
+            ("🫃", GenderMode::MAN, HairStyleMode::NO_HAIR_STYLE),
+            ("🫄", GenderMode::PERSON, HairStyleMode::NO_HAIR_STYLE),
+            ("🤰", GenderMode::WOMAN, HairStyleMode::NO_HAIR_STYLE),
+            ("🐶", GenderMode::NO_GENDER, HairStyleMode::NO_HAIR_STYLE),
+        ];
+        for (emoji, gender, hair_style) in table {
+            let emoji = emojis::get(emoji).unwrap();
+            assert_eq!(GenderMode::new(emoji), gender, "{}", emoji);
+            assert_eq!(HairStyleMode::new(emoji), hair_style, "{}", emoji);
+        }
+    }
+}
@@ -0,0 +1,259 @@
+// SPDX-License-Identifier: MPL-2.0
+// 2024 - Dominic Gerhauser and contributors
+use std::fmt::Write;
+
+use crate::config::ClickMode;
+
+/// Encodings an emoji can be copied as, instead of the emoji itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CopyFormat {
+    Shortcode,
+    Codepoint,
+    HtmlEntity,
+    Rust,
+    Json,
+    Python,
+    UrlEncoded,
+    Name,
+}
+
+impl CopyFormat {
+    pub const ALL: [CopyFormat; 8] = [
+        CopyFormat::Shortcode,
+        CopyFormat::Codepoint,
+        CopyFormat::HtmlEntity,
+        CopyFormat::Rust,
+        CopyFormat::Json,
+        CopyFormat::Python,
+        CopyFormat::UrlEncoded,
+        CopyFormat::Name,
+    ];
+
+    pub fn click_mode(self) -> ClickMode {
+        match self {
+            CopyFormat::Shortcode => ClickMode::AS_SHORTCODE,
+            CopyFormat::Codepoint => ClickMode::AS_CODEPOINT,
+            CopyFormat::HtmlEntity => ClickMode::AS_HTML_ENTITY,
+            CopyFormat::Rust => ClickMode::AS_RUST,
+            CopyFormat::Json => ClickMode::AS_JSON,
+            CopyFormat::Python => ClickMode::AS_PYTHON,
+            CopyFormat::UrlEncoded => ClickMode::AS_URL_ENCODED,
+            CopyFormat::Name => ClickMode::AS_NAME,
+        }
+    }
+
+    /// The first format set in `click_mode`, in the order of [`CopyFormat::ALL`].
+    pub fn from_click_mode(click_mode: ClickMode) -> Option<CopyFormat> {
+        CopyFormat::ALL
+            .into_iter()
+            .find(|format| click_mode.contains(format.click_mode()))
+    }
+
+    /// Formats `text`, returns `None` if there is no shortcode or name to copy.
+    pub fn format(self, text: &str, shortcode: Option<&str>, name: Option<&str>) -> Option<String> {
+        let mut formatted = String::with_capacity(text.len() * 4);
+        match self {
+            CopyFormat::Shortcode => return shortcode.map(|shortcode| format!(":{shortcode}:")),
+            CopyFormat::Name => return name.map(str::to_string),
+            CopyFormat::Codepoint => {
+                for (idx, c) in text.chars().enumerate() {
+                    if idx > 0 {
+                        formatted.push(' ');
+                    }
+                    let _ = write!(formatted, "U+{:04X}", u32::from(c));
+                }
+            }
+            CopyFormat::HtmlEntity => {
+                for c in text.chars() {
+                    let _ = write!(formatted, "&#x{:X};", u32::from(c));
+                }
+            }
+            CopyFormat::Rust => {
+                for c in text.chars() {
+                    let _ = write!(formatted, "\\u{{{:X}}}", u32::from(c));
+                }
+            }
+            CopyFormat::Json => {
+                for unit in text.encode_utf16() {
+                    let _ = write!(formatted, "\\u{unit:04X}");
+                }
+            }
+            CopyFormat::Python => {
+                for c in text.chars() {
+                    let _ = match u32::from(c) {
+                        c @ ..=0xffff => write!(formatted, "\\u{c:04X}"),
+                        c => write!(formatted, "\\U{c:08X}"),
+                    };
+                }
+            }
+            CopyFormat::UrlEncoded => {
+                for b in text.bytes() {
+                    if b.is_ascii_alphanumeric() || b"-_.~".contains(&b) {
+                        formatted.push(char::from(b));
+                    } else {
+                        let _ = write!(formatted, "%{b:02X}");
+                    }
+                }
+            }
+        }
+        Some(formatted)
+    }
+}
+
+/// Wraps `text` in a span for rich text editors, which shows it with `font_family`
+/// and gives screen readers the `name` of the emoji.
+pub(crate) fn html(text: &str, font_family: &str, name: Option<&str>) -> String {
+    let font_family = font_family.replace('\\', "\\\\").replace('"', "\\\"");
+    let mut html = format!(
+        "<span style=\"font-family: &quot;{}&quot;\"",
+        html_escape(&font_family)
+    );
+    if let Some(name) = name {
+        let name = html_escape(name);
+        let _ = write!(html, " role=\"img\" aria-label=\"{name}\" title=\"{name}\"");
+    }
+    let _ = write!(html, ">{}</span>", html_escape(text));
+    html
+}
+
+fn html_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_table() {
+        let heart = "\u{2764}\u{FE0F}";
+        let rainbow_flag = "\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}";
+        let table = [
+            (CopyFormat::Codepoint, heart, "U+2764 U+FE0F"),
+            (
+                CopyFormat::Codepoint,
+                rainbow_flag,
+                "U+1F3F3 U+FE0F U+200D U+1F308",
+            ),
+            (CopyFormat::HtmlEntity, heart, "&#x2764;&#xFE0F;"),
+            (
+                CopyFormat::HtmlEntity,
+                rainbow_flag,
+                "&#x1F3F3;&#xFE0F;&#x200D;&#x1F308;",
+            ),
+            (CopyFormat::Rust, heart, r"\u{2764}\u{FE0F}"),
+            (
+                CopyFormat::Rust,
+                rainbow_flag,
+                r"\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}",
+            ),
+            (CopyFormat::Json, heart, r"\u2764\uFE0F"),
+            // surrogate pairs outside the basic multilingual plane
+            (
+                CopyFormat::Json,
+                rainbow_flag,
+                r"\uD83C\uDFF3\uFE0F\u200D\uD83C\uDF08",
+            ),
+            (CopyFormat::Python, heart, r"\u2764\uFE0F"),
+            (
+                CopyFormat::Python,
+                rainbow_flag,
+                r"\U0001F3F3\uFE0F\u200D\U0001F308",
+            ),
+            (CopyFormat::UrlEncoded, heart, "%E2%9D%A4%EF%B8%8F"),
+            (
+                CopyFormat::UrlEncoded,
+                rainbow_flag,
+                "%F0%9F%8F%B3%EF%B8%8F%E2%80%8D%F0%9F%8C%88",
+            ),
+            (CopyFormat::UrlEncoded, "a-b c", "a-b%20c"),
+        ];
+        for (format, text, expected) in table {
+            assert_eq!(
+                format.format(text, None, None).as_deref(),
+                Some(expected),
+                "{:?} {:?}",
+                format,
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn format_shortcode_and_name() {
+        let heart = "\u{2764}\u{FE0F}";
+        assert_eq!(
+            CopyFormat::Shortcode.format(heart, Some("heart"), Some("red heart")),
+            Some(":heart:".to_string())
+        );
+        assert_eq!(
+            CopyFormat::Name.format(heart, Some("heart"), Some("red heart")),
+            Some("red heart".to_string())
+        );
+        assert_eq!(CopyFormat::Shortcode.format(heart, None, None), None);
+        assert_eq!(CopyFormat::Name.format(heart, None, None), None);
+    }
+
+    #[test]
+    fn click_modes() {
+        for format in CopyFormat::ALL {
+            assert_eq!(
+                CopyFormat::from_click_mode(format.click_mode() | ClickMode::COPY),
+                Some(format)
+            );
+        }
+        assert_eq!(CopyFormat::from_click_mode(ClickMode::COPY), None);
+    }
+
+    #[test]
+    fn html_table() {
+        let table = [
+            (
+                "Noto Color Emoji",
+                None,
+                r#"<span style="font-family: &quot;Noto Color Emoji&quot;">🎉</span>"#,
+            ),
+            (
+                "Noto Color Emoji",
+                Some("party popper"),
+                r#"<span style="font-family: &quot;Noto Color Emoji&quot;" role="img" aria-label="party popper" title="party popper">🎉</span>"#,
+            ),
+            (
+                "Noto Color Emoji",
+                Some(r#"say "hi" & <wave>"#),
+                r#"<span style="font-family: &quot;Noto Color Emoji&quot;" role="img" aria-label="say &quot;hi&quot; &amp; &lt;wave&gt;" title="say &quot;hi&quot; &amp; &lt;wave&gt;">🎉</span>"#,
+            ),
+            // the quote is escaped for CSS first, then for the attribute
+            (
+                r#"My "Emoji" Font"#,
+                None,
+                r#"<span style="font-family: &quot;My \&quot;Emoji\&quot; Font&quot;">🎉</span>"#,
+            ),
+            (
+                r"Back\slash",
+                None,
+                r#"<span style="font-family: &quot;Back\\slash&quot;">🎉</span>"#,
+            ),
+        ];
+        for (font_family, name, expected) in table {
+            assert_eq!(
+                html("🎉", font_family, name),
+                expected,
+                "{:?} {:?}",
+                font_family,
+                name
+            );
+        }
+    }
+}
@@ -0,0 +1,223 @@
+// SPDX-License-Identifier: MPL-2.0
+// 2024 - Dominic Gerhauser and contributors
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::config::Config;
+
+const HEADER: &str =
+    "Generated by cosmic-ext-applet-emoji-selector from the history, collections and snippets.";
+
+/// Shortcuts for the favorite emojis in other applications.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    XCompose,
+    Espanso,
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 2] = [ExportFormat::XCompose, ExportFormat::Espanso];
+
+    /// The command line argument which prints the export instead of running the applet.
+    pub fn arg(self) -> &'static str {
+        match self {
+            ExportFormat::XCompose => "export-xcompose",
+            ExportFormat::Espanso => "export-espanso",
+        }
+    }
+
+    /// The file in the home directory the popup exports to.
+    pub fn file_name(self) -> &'static str {
+        match self {
+            ExportFormat::XCompose => "emoji-favorites.XCompose",
+            ExportFormat::Espanso => "emoji-favorites.yml",
+        }
+    }
+
+    pub fn export(self, config: &Config) -> String {
+        match self {
+            ExportFormat::XCompose => xcompose(config),
+            ExportFormat::Espanso => espanso(config),
+        }
+    }
+}
+
+/// The text a shortcode is replaced with.
+struct Shortcut<'a> {
+    text: &'a str,
+    /// The emoji or snippet name.
+    name: &'a str,
+}
+
+/// Every shortcode of the favorite emojis and snippets, sorted so that the output only changes
+/// when the favorites do. Emojis without a shortcode, e.g. skin tone variants, are skipped.
+fn shortcodes(config: &Config) -> BTreeMap<String, Shortcut<'_>> {
+    let collections = config
+        .collections
+        .iter()
+        .flat_map(|collection| collection.emojis.iter());
+    let mut shortcodes: BTreeMap<String, Shortcut<'_>> = config
+        .last_used
+        .iter()
+        .chain(collections)
+        .flat_map(|&emoji| {
+            emoji.shortcodes().map(move |shortcode| {
+                let shortcut = Shortcut {
+                    text: emoji.as_str(),
+                    name: emoji.name(),
+                };
+                (shortcode.to_string(), shortcut)
+            })
+        })
+        .collect();
+    // the shortcodes of emojis are kept, so the same shortcode types the same emoji everywhere
+    for snippet in &config.snippets {
+        let shortcode = snippet_shortcode(&snippet.name);
+        if !shortcode.is_empty() {
+            shortcodes.entry(shortcode).or_insert(Shortcut {
+                text: &snippet.text,
+                name: &snippet.name,
+            });
+        }
+    }
+    shortcodes
+}
+
+/// A shortcode for the name of a snippet, e.g. `shrug_face` for "Shrug face".
+/// Other characters than ASCII letters, digits, `+` and `-` separate words.
+fn snippet_shortcode(name: &str) -> String {
+    let mut shortcode = String::with_capacity(name.len());
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() || c == '+' || c == '-' {
+            shortcode.push(c.to_ascii_lowercase());
+        } else if !shortcode.is_empty() && !shortcode.ends_with('_') {
+            shortcode.push('_');
+        }
+    }
+    shortcode.truncate(shortcode.trim_end_matches('_').len());
+    shortcode
+}
+
+/// Compose sequences like `<Multi_key> <colon> <t> <a> <d> <a> <colon> : "🎉"`,
+/// to be included from `~/.XCompose`.
+/// The closing colon keeps shortcodes like `smile` from being a prefix of others like `smiley`.
+fn xcompose(config: &Config) -> String {
+    let mut xcompose = format!("# {HEADER}\n");
+    for (shortcode, shortcut) in shortcodes(config) {
+        xcompose.push_str("<Multi_key> <colon>");
+        for c in shortcode.chars() {
+            let _ = match c {
+                '_' => write!(xcompose, " <underscore>"),
+                '+' => write!(xcompose, " <plus>"),
+                '-' => write!(xcompose, " <minus>"),
+                c if c.is_ascii_alphanumeric() => write!(xcompose, " <{c}>"),
+                c => write!(xcompose, " <U{:04X}>", u32::from(c)),
+            };
+        }
+        xcompose.push_str(" <colon> : \"");
+        for c in shortcut.text.chars() {
+            let _ = match c {
+                '"' | '\\' => write!(xcompose, "\\{c}"),
+                c if c.is_control() => write!(xcompose, "\\{:03o}", u32::from(c)),
+                c => write!(xcompose, "{c}"),
+            };
+        }
+        let name = shortcut.name.replace(char::is_control, " ");
+        let _ = writeln!(xcompose, "\" # {name}");
+    }
+    xcompose
+}
+
+/// An espanso match file with triggers like `:tada:`.
+fn espanso(config: &Config) -> String {
+    let mut espanso = format!("# {HEADER}\nmatches:\n");
+    for (shortcode, shortcut) in shortcodes(config) {
+        // JSON strings are valid double-quoted YAML strings
+        let trigger = serde_json::to_string(&format!(":{shortcode}:")).unwrap_or_default();
+        let replace = serde_json::to_string(shortcut.text).unwrap_or_default();
+        let _ = writeln!(espanso, "  - trigger: {trigger}\n    replace: {replace}");
+    }
+    espanso
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Collection, Snippet};
+
+    fn config() -> Config {
+        let emoji = |emoji| emojis::get(emoji).unwrap();
+        let snippet = |name: &str, text: &str| Snippet {
+            name: name.to_string(),
+            text: text.to_string(),
+        };
+        Config {
+            // skin tone variants have no shortcode
+            last_used: [emoji("😃"), emoji("🎉"), emoji("👍"), emoji("👍🏽")].into(),
+            collections: vec![Collection {
+                name: "Reactions".to_string(),
+                icon: emoji("👍"),
+                emojis: vec![emoji("👎"), emoji("😍"), emoji("🎉")],
+            }],
+            snippets: vec![
+                snippet("Shrug face", r"¯\_(ツ)_/¯"),
+                // the shortcode of the emoji is kept
+                snippet("tada", "🎊"),
+                snippet(r#"Say "hi"!"#, "\"hi\"\tthere"),
+                snippet("😀😀", "no shortcode"),
+            ],
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn export_xcompose() {
+        let expected = r#"# Generated by cosmic-ext-applet-emoji-selector from the history, collections and snippets.
+<Multi_key> <colon> <plus> <1> <colon> : "👍" # thumbs up
+<Multi_key> <colon> <minus> <1> <colon> : "👎" # thumbs down
+<Multi_key> <colon> <h> <e> <a> <r> <t> <underscore> <e> <y> <e> <s> <colon> : "😍" # smiling face with heart-eyes
+<Multi_key> <colon> <s> <a> <y> <underscore> <h> <i> <colon> : "\"hi\"\011there" # Say "hi"!
+<Multi_key> <colon> <s> <h> <r> <u> <g> <underscore> <f> <a> <c> <e> <colon> : "¯\\_(ツ)_/¯" # Shrug face
+<Multi_key> <colon> <s> <m> <i> <l> <e> <y> <colon> : "😃" # grinning face with big eyes
+<Multi_key> <colon> <t> <a> <d> <a> <colon> : "🎉" # party popper
+<Multi_key> <colon> <t> <h> <u> <m> <b> <s> <d> <o> <w> <n> <colon> : "👎" # thumbs down
+<Multi_key> <colon> <t> <h> <u> <m> <b> <s> <u> <p> <colon> : "👍" # thumbs up
+"#;
+        assert_eq!(ExportFormat::XCompose.export(&config()), expected);
+    }
+
+    #[test]
+    fn export_espanso() {
+        let expected = r#"# Generated by cosmic-ext-applet-emoji-selector from the history, collections and snippets.
+matches:
+  - trigger: ":+1:"
+    replace: "👍"
+  - trigger: ":-1:"
+    replace: "👎"
+  - trigger: ":heart_eyes:"
+    replace: "😍"
+  - trigger: ":say_hi:"
+    replace: "\"hi\"\tthere"
+  - trigger: ":shrug_face:"
+    replace: "¯\\_(ツ)_/¯"
+  - trigger: ":smiley:"
+    replace: "😃"
+  - trigger: ":tada:"
+    replace: "🎉"
+  - trigger: ":thumbsdown:"
+    replace: "👎"
+  - trigger: ":thumbsup:"
+    replace: "👍"
+"#;
+        assert_eq!(ExportFormat::Espanso.export(&config()), expected);
+    }
+
+    #[test]
+    fn snippet_shortcodes() {
+        assert_eq!(snippet_shortcode("Shrug face"), "shrug_face");
+        assert_eq!(snippet_shortcode("  C++ -- tips!  "), "c++_--_tips");
+        assert_eq!(snippet_shortcode("snake_case"), "snake_case");
+        assert_eq!(snippet_shortcode("Grüße"), "gr_e");
+        assert_eq!(snippet_shortcode("😀😀"), "");
+    }
+}
@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: MPL-2.0
+// 2024 - Dominic Gerhauser and contributors
+
+/// Search prefix to compose a flag from its code, e.g. `flag:gb-sct`.
+pub const SEARCH_PREFIX: &str = "flag:";
+
+const REGIONAL_INDICATOR_A: u32 = 0x1f1e6;
+const BLACK_FLAG: char = '\u{1f3f4}';
+const TAG_LATIN_SMALL_A: u32 = 0xe0061;
+const TAG_DIGIT_ZERO: u32 = 0xe0030;
+const CANCEL_TAG: char = '\u{e007f}';
+
+/// A composed flag, which is not necessarily recommended for general interchange.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Flag {
+    pub text: String,
+    /// The RGI emoji, `None` if most fonts will not show the flag.
+    pub emoji: Option<&'static emojis::Emoji>,
+}
+
+/// Composes a flag from a region code like `de`, or a subdivision code like `gb-sct`.
+/// Returns `None` if the code is malformed.
+pub(crate) fn compose(code: &str) -> Option<Flag> {
+    let code = code.trim().to_ascii_lowercase();
+    let (region, subdivision) = match code.split_once('-') {
+        Some((region, subdivision)) => (region, Some(subdivision)),
+        None => (code.as_str(), None),
+    };
+    if region.len() != 2 || !region.bytes().all(|b| b.is_ascii_lowercase()) {
+        return None;
+    }
+    let text = match subdivision {
+        None => region
+            .bytes()
+            .map(|b| char::from_u32(REGIONAL_INDICATOR_A + u32::from(b - b'a')))
+            .collect::<Option<String>>()?,
+        Some(subdivision) => {
+            if !(1..=4).contains(&subdivision.len())
+                || !subdivision.bytes().all(|b| b.is_ascii_alphanumeric())
+            {
+                return None;
+            }
+            let mut text = String::from(BLACK_FLAG);
+            for b in region.bytes().chain(subdivision.bytes()) {
+                let tag = match b {
+                    b'0'..=b'9' => TAG_DIGIT_ZERO + u32::from(b - b'0'),
+                    _ => TAG_LATIN_SMALL_A + u32::from(b - b'a'),
+                };
+                text.push(char::from_u32(tag)?);
+            }
+            text.push(CANCEL_TAG);
+            text
+        }
+    };
+    let emoji = emojis::get(&text);
+    Some(Flag { text, emoji })
+}
+
+/// The flag code of a search like `flag:gb-sct`.
+pub(crate) fn search_code(search: &str) -> Option<&str> {
+    let prefix = search.get(..SEARCH_PREFIX.len())?;
+    prefix
+        .eq_ignore_ascii_case(SEARCH_PREFIX)
+        .then(|| &search[SEARCH_PREFIX.len()..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compose_region_flags() {
+        let germany = compose("de").unwrap();
+        assert_eq!(germany.text, "\u{1f1e9}\u{1f1ea}");
+        assert_eq!(germany.emoji, emojis::get("🇩🇪"));
+        assert_eq!(compose(" DE ").unwrap(), germany);
+        // valid regional indicators, but no region
+        let unknown = compose("xx").unwrap();
+        assert_eq!(unknown.text, "\u{1f1fd}\u{1f1fd}");
+        assert_eq!(unknown.emoji, None);
+    }
+
+    #[test]
+    fn compose_subdivision_flags() {
+        let scotland = compose("gb-sct").unwrap();
+        assert_eq!(
+            scotland.text,
+            "\u{1f3f4}\u{e0067}\u{e0062}\u{e0073}\u{e0063}\u{e0074}\u{e007f}"
+        );
+        assert_eq!(scotland.emoji.map(|e| e.name()), Some("flag: Scotland"));
+        // well-formed, but not recommended for general interchange
+        let texas = compose("us-tx").unwrap();
+        assert_eq!(
+            texas.text,
+            "\u{1f3f4}\u{e0075}\u{e0073}\u{e0074}\u{e0078}\u{e007f}"
+        );
+        assert_eq!(texas.emoji, None);
+        assert_eq!(
+            compose("fr-75").unwrap().text,
+            "\u{1f3f4}\u{e0066}\u{e0072}\u{e0037}\u{e0035}\u{e007f}"
+        );
+    }
+
+    #[test]
+    fn reject_malformed_codes() {
+        for code in [
+            "", "d", "deu", "d3", "gb-", "gb-sct-x", "gb-scotl", "gb-s t", "ü", "üa",
+        ] {
+            assert_eq!(compose(code), None, "{:?}", code);
+        }
+    }
+
+    #[test]
+    fn search_prefix() {
+        assert_eq!(search_code("flag:gb-sct"), Some("gb-sct"));
+        assert_eq!(search_code("Flag:de"), Some("de"));
+        assert_eq!(search_code("flag:"), Some(""));
+        assert_eq!(search_code("flags"), None);
+        assert_eq!(search_code("fläg:de"), None);
+        assert_eq!(search_code("de"), None);
+    }
+}
@@ -6,6 +6,7 @@ use std::path::PathBuf;
 use std::{env, fs};
 
 use crate::app::Window;
+use crate::export::ExportFormat;
 
 use config::{Annotation, Config, CONFIG_VERSION};
 use cosmic::cosmic_config;
@@ -14,10 +15,17 @@ mod config;
 use app::Flags;
 
 mod app;
+mod copy_format;
+mod export;
+mod flag;
 mod google_ordering;
 mod localize;
+mod render;
+mod stats;
 mod style_copy;
+mod text_transform;
 mod utils;
+mod virtual_keyboard;
 mod widget_copy;
 
 fn main() -> cosmic::iced::Result {
@@ -113,6 +121,16 @@ fn main() -> cosmic::iced::Result {
             (None, Config::default())
         }
     };
+
+    let arg = env::args().nth(1);
+    if let Some(format) = ExportFormat::ALL
+        .into_iter()
+        .find(|format| arg.as_deref() == Some(format.arg()))
+    {
+        print!("{}", format.export(&config));
+        return Ok(());
+    }
+
     let flags = Flags {
         config_handler,
         config,
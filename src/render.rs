@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: MPL-2.0
+// 2024 - Dominic Gerhauser and contributors
+use std::borrow::Cow;
+use std::error::Error;
+use std::sync::Mutex;
+
+use cosmic::iced::clipboard::mime::AsMimeTypes;
+use cosmic::iced_renderer::graphics::text::cosmic_text::{
+    Attrs, Buffer, Color, Family, Metrics, Shaping, SwashCache,
+};
+use cosmic::iced_renderer::graphics::text::font_system;
+use once_cell::sync::Lazy;
+
+/// Image sizes offered in the context menu, in pixels.
+pub const SIZES: [u32; 5] = [32, 64, 128, 256, 512];
+
+/// Glyphs rendered for the images, the fonts are shared with the renderer of the applet.
+static SWASH_CACHE: Lazy<Mutex<SwashCache>> = Lazy::new(|| Mutex::new(SwashCache::new()));
+
+/// Position, size and color of a rasterized glyph area.
+type GlyphRect = (i32, i32, u32, u32, Color);
+
+/// A rendered emoji, offered to the clipboard as `image/png` and as text.
+#[derive(Clone, Debug)]
+pub struct EmojiImage {
+    pub png: Vec<u8>,
+    pub text: String,
+}
+
+impl AsMimeTypes for EmojiImage {
+    fn available(&self) -> Cow<'static, [String]> {
+        Cow::Owned(vec![
+            "image/png".to_string(),
+            "text/plain;charset=utf-8".to_string(),
+            "text/plain".to_string(),
+            "UTF8_STRING".to_string(),
+        ])
+    }
+
+    fn as_bytes(&self, mime_type: &str) -> Option<Cow<'static, [u8]>> {
+        match mime_type {
+            "image/png" => Some(Cow::Owned(self.png.clone())),
+            "text/plain;charset=utf-8" | "text/plain" | "UTF8_STRING" => {
+                Some(Cow::Owned(self.text.as_bytes().to_vec()))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Rasterizes `text` with `font_family` into a square PNG of `size` pixels,
+/// scaled to fit and centered on a transparent background.
+pub(crate) fn render_png(
+    text: &str,
+    font_family: &str,
+    size: u32,
+) -> Result<Vec<u8>, Box<dyn Error>> {
+    // the glyph extents differ between fonts, so they are measured first
+    let mut bounds: Option<(i32, i32, i32, i32)> = None;
+    for (x, y, w, h, _) in glyph_rects(text, font_family, size as f32)? {
+        let (min_x, min_y, max_x, max_y) = bounds.get_or_insert((x, y, x, y));
+        *min_x = (*min_x).min(x);
+        *min_y = (*min_y).min(y);
+        *max_x = (*max_x).max(x + w as i32 - 1);
+        *max_y = (*max_y).max(y + h as i32 - 1);
+    }
+    let Some((min_x, min_y, max_x, max_y)) = bounds else {
+        return Err(format!("nothing was rendered with the font {:?}", font_family).into());
+    };
+
+    let (width, height) = ((max_x - min_x + 1) as f32, (max_y - min_y + 1) as f32);
+    let scale = size as f32 / width.max(height);
+    let offset_x = (size as f32 - width * scale) / 2. - min_x as f32 * scale;
+    let offset_y = (size as f32 - height * scale) / 2. - min_y as f32 * scale;
+    let (offset_x, offset_y) = (offset_x.round() as i32, offset_y.round() as i32);
+
+    let mut pixels = vec![0u8; (size * size * 4) as usize];
+    for (x, y, w, h, color) in glyph_rects(text, font_family, size as f32 * scale)? {
+        for y in y + offset_y..y + offset_y + h as i32 {
+            for x in x + offset_x..x + offset_x + w as i32 {
+                if x < 0 || y < 0 || x >= size as i32 || y >= size as i32 {
+                    continue;
+                }
+                let idx = (y as usize * size as usize + x as usize) * 4;
+                blend(&mut pixels[idx..idx + 4], color.as_rgba());
+            }
+        }
+    }
+
+    let mut png = Vec::new();
+    let mut encoder = png::Encoder::new(&mut png, size, size);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&pixels)?;
+    writer.finish()?;
+    Ok(png)
+}
+
+/// Shapes `text` and returns the rectangles of its glyphs which are not fully transparent.
+///
+/// The font system is shared with the renderer of the applet, so it is only locked
+/// while shaping and rasterizing the glyphs, not while the image is composed.
+fn glyph_rects(
+    text: &str,
+    font_family: &str,
+    font_size: f32,
+) -> Result<Vec<GlyphRect>, Box<dyn Error>> {
+    let mut swash_cache = SWASH_CACHE.lock().map_err(|_| "glyph cache is poisoned")?;
+    let mut fonts = font_system()
+        .write()
+        .map_err(|_| "font system is poisoned")?;
+    let font_system = fonts.raw();
+
+    let mut buffer = Buffer::new(font_system, Metrics::new(font_size, font_size));
+    buffer.set_size(font_system, None, None);
+    buffer.set_text(
+        font_system,
+        text,
+        Attrs::new().family(Family::Name(font_family)),
+        Shaping::Advanced,
+    );
+    buffer.shape_until_scroll(font_system, false);
+    let mut rects = Vec::new();
+    // black is only used for glyphs without colors of their own
+    buffer.draw(
+        font_system,
+        &mut swash_cache,
+        Color::rgb(0, 0, 0),
+        |x, y, w, h, color| {
+            if color.a() != 0 {
+                rects.push((x, y, w, h, color));
+            }
+        },
+    );
+    Ok(rects)
+}
+
+/// Draws `src` over the straight alpha RGBA pixel `dst`.
+fn blend(dst: &mut [u8], src: [u8; 4]) {
+    let src_a = src[3] as f32 / 255.;
+    let dst_a = dst[3] as f32 / 255.;
+    let out_a = src_a + dst_a * (1. - src_a);
+    if out_a <= 0. {
+        return;
+    }
+    for channel in 0..3 {
+        let src_c = src[channel] as f32 / 255.;
+        let dst_c = dst[channel] as f32 / 255.;
+        let out_c = (src_c * src_a + dst_c * dst_a * (1. - src_a)) / out_a;
+        dst[channel] = (out_c * 255.).round() as u8;
+    }
+    dst[3] = (out_a * 255.).round() as u8;
+}
@@ -0,0 +1,265 @@
+// SPDX-License-Identifier: MPL-2.0
+// 2024 - Dominic Gerhauser and contributors
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Days of usage data which are kept.
+pub const RETENTION_DAYS: u32 = 366;
+
+const USAGE_FILE: &str = "usage.json";
+
+/// How often emojis were copied on a single day.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct UsageDay {
+    /// Days since the Unix epoch in UTC.
+    pub day: u32,
+    pub counts: Vec<(&'static emojis::Emoji, u32)>,
+}
+
+pub(crate) fn today() -> u32 {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+    (secs / 86400) as u32
+}
+
+/// The directory of the usage data and CSV exports.
+/// The usage changes with every copy, so it is kept out of the config.
+pub(crate) fn data_dir() -> Option<PathBuf> {
+    let data_home = std::env::var_os("XDG_DATA_HOME")
+        .filter(|data_home| !data_home.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share"))
+        })?;
+    Some(data_home.join(crate::app::ID))
+}
+
+/// Reads the usage data from `dir`, `None` if nothing was recorded yet.
+pub(crate) fn load(dir: &Path) -> io::Result<Option<Vec<UsageDay>>> {
+    match fs::read(dir.join(USAGE_FILE)) {
+        Ok(data) => Ok(Some(serde_json::from_slice(&data)?)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Loads the usage data, moving `legacy` from the config of older versions on the first start.
+pub(crate) fn load_or_migrate(dir: &Path, legacy: &[UsageDay]) -> io::Result<Vec<UsageDay>> {
+    match load(dir)? {
+        Some(usage) => Ok(usage),
+        None => {
+            if !legacy.is_empty() {
+                save(dir, legacy)?;
+            }
+            Ok(legacy.to_vec())
+        }
+    }
+}
+
+/// Replaces the usage data in `dir`.
+/// The data is renamed into place, so other applet instances never read a partial file.
+pub(crate) fn save(dir: &Path, usage: &[UsageDay]) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    let tmp = dir.join(format!("{}.{}.tmp", USAGE_FILE, std::process::id()));
+    fs::write(&tmp, serde_json::to_vec(usage)?)?;
+    fs::rename(&tmp, dir.join(USAGE_FILE))
+}
+
+/// Writes the usage as CSV into `dir`, numbering the file instead of replacing earlier exports.
+pub(crate) fn export_csv(dir: &Path, usage: &[UsageDay]) -> io::Result<PathBuf> {
+    crate::utils::create_new_file(dir, "emoji-usage", "csv", to_csv(usage).as_bytes())
+}
+
+/// Counts one use of `emoji` on `day` and drops days older than [`RETENTION_DAYS`].
+pub(crate) fn record(usage: &mut Vec<UsageDay>, emoji: &'static emojis::Emoji, day: u32) {
+    usage.retain(|usage_day| usage_day.day + RETENTION_DAYS > day);
+    let usage_day = match usage.iter().position(|usage_day| usage_day.day == day) {
+        Some(idx) => &mut usage[idx],
+        None => {
+            let idx = usage.partition_point(|usage_day| usage_day.day < day);
+            usage.insert(
+                idx,
+                UsageDay {
+                    day,
+                    counts: Vec::new(),
+                },
+            );
+            &mut usage[idx]
+        }
+    };
+    match usage_day.counts.iter_mut().find(|(e, _)| *e == emoji) {
+        Some((_, count)) => *count += 1,
+        None => usage_day.counts.push((emoji, 1)),
+    }
+}
+
+/// The most used emojis of the last `days` days, most used first.
+pub(crate) fn top_emojis(
+    usage: &[UsageDay],
+    today: u32,
+    days: u32,
+    limit: usize,
+) -> Vec<(&'static emojis::Emoji, u32)> {
+    let mut totals: Vec<(&'static emojis::Emoji, u32)> = Vec::new();
+    for usage_day in usage
+        .iter()
+        .filter(|usage_day| usage_day.day <= today && usage_day.day + days > today)
+    {
+        for &(emoji, count) in &usage_day.counts {
+            match totals.iter_mut().find(|(e, _)| *e == emoji) {
+                Some((_, total)) => *total += count,
+                None => totals.push((emoji, count)),
+            }
+        }
+    }
+    // stable sort keeps the first used emoji first on ties
+    totals.sort_by(|(_, a), (_, b)| b.cmp(a));
+    totals.truncate(limit);
+    totals
+}
+
+/// Total uses per group, in the order of [`emojis::Group::iter`].
+pub(crate) fn group_breakdown(usage: &[UsageDay]) -> Vec<(emojis::Group, u32)> {
+    let mut totals: Vec<(emojis::Group, u32)> =
+        emojis::Group::iter().map(|group| (group, 0)).collect();
+    for usage_day in usage {
+        for &(emoji, count) in &usage_day.counts {
+            if let Some((_, total)) = totals.iter_mut().find(|(g, _)| *g == emoji.group()) {
+                *total += count;
+            }
+        }
+    }
+    totals
+}
+
+/// Returns the current and the longest streak of consecutive days with usage.
+/// The current streak is not broken until a whole day passes without usage.
+pub(crate) fn streaks(usage: &[UsageDay], today: u32) -> (u32, u32) {
+    let mut longest = 0;
+    let mut current = 0;
+    let mut last_day = None;
+    for usage_day in usage
+        .iter()
+        .filter(|usage_day| !usage_day.counts.is_empty())
+    {
+        current = match last_day {
+            Some(last_day) if last_day + 1 == usage_day.day => current + 1,
+            _ => 1,
+        };
+        longest = longest.max(current);
+        last_day = Some(usage_day.day);
+    }
+    let current = match last_day {
+        Some(last_day) if last_day + 1 >= today => current,
+        _ => 0,
+    };
+    (current, longest)
+}
+
+/// Exports the usage as CSV with one line per emoji and day.
+pub(crate) fn to_csv(usage: &[UsageDay]) -> String {
+    let mut csv = String::from("date,emoji,name,group,count\n");
+    for usage_day in usage {
+        let (year, month, day) = civil_from_days(usage_day.day);
+        for &(emoji, count) in &usage_day.counts {
+            csv.push_str(&format!(
+                "{year:04}-{month:02}-{day:02},{},{},{:?},{count}\n",
+                csv_field(emoji.as_str()),
+                csv_field(emoji.name()),
+                emoji.group(),
+            ));
+        }
+    }
+    csv
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+// http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: u32) -> (i64, u32, u32) {
+    let z = days as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usage_days(days: &[u32]) -> Vec<UsageDay> {
+        let emoji = emojis::get("🎉").unwrap();
+        days.iter()
+            .map(|&day| UsageDay {
+                day,
+                counts: vec![(emoji, 1)],
+            })
+            .collect()
+    }
+
+    #[test]
+    fn civil_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(58), (1970, 2, 28));
+        assert_eq!(civil_from_days(59), (1970, 3, 1));
+        assert_eq!(civil_from_days(11016), (2000, 2, 29));
+        assert_eq!(civil_from_days(19722), (2023, 12, 31));
+        assert_eq!(civil_from_days(19723), (2024, 1, 1));
+    }
+
+    #[test]
+    fn streaks_of_consecutive_days() {
+        assert_eq!(streaks(&[], 100), (0, 0));
+        let usage = usage_days(&[90, 91, 92, 95, 96]);
+        assert_eq!(streaks(&usage, 96), (2, 3));
+        // the streak lasts until a whole day passes without usage
+        assert_eq!(streaks(&usage, 97), (2, 3));
+        assert_eq!(streaks(&usage, 98), (0, 3));
+        // days without counts do not continue a streak
+        let mut usage = usage_days(&[1, 2, 4]);
+        usage.insert(
+            2,
+            UsageDay {
+                day: 3,
+                counts: Vec::new(),
+            },
+        );
+        assert_eq!(streaks(&usage, 4), (1, 2));
+    }
+
+    #[test]
+    fn save_and_export_in_data_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path().join("data");
+        let legacy = usage_days(&[19723]);
+        assert_eq!(load(&dir).unwrap(), None);
+        assert_eq!(load_or_migrate(&dir, &legacy).unwrap(), legacy);
+        // once migrated, the legacy data is ignored
+        assert_eq!(load_or_migrate(&dir, &[]).unwrap(), legacy);
+
+        let first = export_csv(&dir, &legacy).unwrap();
+        let second = export_csv(&dir, &[]).unwrap();
+        assert_eq!(first, dir.join("emoji-usage.csv"));
+        assert_eq!(second, dir.join("emoji-usage-1.csv"));
+        assert_eq!(
+            fs::read_to_string(first).unwrap(),
+            "date,emoji,name,group,count\n2024-01-01,🎉,party popper,Activities,1\n"
+        );
+    }
+}
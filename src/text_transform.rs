@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: MPL-2.0
+// 2024 - Dominic Gerhauser and contributors
+
+const REGIONAL_INDICATOR_A: u32 = 0x1f1e6;
+const SQUARED_A: u32 = 0x1f130;
+const NEGATIVE_SQUARED_A: u32 = 0x1f170;
+const KEYCAP: &str = "\u{fe0f}\u{20e3}";
+const CLAP: &str = "\u{1f44f}";
+/// Keeps adjacent regional indicators from being shown as a flag.
+const ZERO_WIDTH_SPACE: char = '\u{200b}';
+
+/// Ways to turn typed text into emojis.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Transform {
+    /// Letters as regional indicators, e.g. 🇭​🇮.
+    RegionalIndicators,
+    /// Letters as squared letters, e.g. 🄷🄸.
+    Squared,
+    /// Letters as negative squared letters, e.g. 🅷🅸.
+    NegativeSquared,
+    /// Digits, `#` and `*` as keycaps, e.g. 4️⃣2️⃣.
+    Keycaps,
+    /// A clap between words, e.g. this 👏 is 👏 it.
+    Clap,
+}
+
+impl Transform {
+    pub const ALL: [Transform; 5] = [
+        Transform::RegionalIndicators,
+        Transform::Squared,
+        Transform::NegativeSquared,
+        Transform::Keycaps,
+        Transform::Clap,
+    ];
+
+    /// Transforms `text`, characters without an emoji are kept as they are.
+    pub fn apply(self, text: &str) -> String {
+        match self {
+            Transform::RegionalIndicators => {
+                let mut transformed = String::with_capacity(text.len() * 5);
+                let mut previous_indicator = false;
+                for c in text.chars() {
+                    match letter(c, REGIONAL_INDICATOR_A) {
+                        Some(indicator) => {
+                            if previous_indicator {
+                                transformed.push(ZERO_WIDTH_SPACE);
+                            }
+                            transformed.push(indicator);
+                            previous_indicator = true;
+                        }
+                        None => {
+                            transformed.push(c);
+                            previous_indicator = false;
+                        }
+                    }
+                }
+                transformed
+            }
+            Transform::Squared => text
+                .chars()
+                .map(|c| letter(c, SQUARED_A).unwrap_or(c))
+                .collect(),
+            Transform::NegativeSquared => text
+                .chars()
+                .map(|c| letter(c, NEGATIVE_SQUARED_A).unwrap_or(c))
+                .collect(),
+            Transform::Keycaps => {
+                let mut transformed = String::with_capacity(text.len() * 7);
+                for c in text.chars() {
+                    transformed.push(c);
+                    if c.is_ascii_digit() || c == '#' || c == '*' {
+                        transformed.push_str(KEYCAP);
+                    }
+                }
+                transformed
+            }
+            Transform::Clap => text
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(&format!(" {CLAP} ")),
+        }
+    }
+}
+
+/// The character of the block starting at `first` for the ASCII letter `c`.
+fn letter(c: char, first: u32) -> Option<char> {
+    if !c.is_ascii_alphabetic() {
+        return None;
+    }
+    char::from_u32(first + u32::from(c.to_ascii_lowercase() as u8 - b'a'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regional_indicators() {
+        assert_eq!(
+            Transform::RegionalIndicators.apply("Hi there 42#!"),
+            concat!(
+                "\u{1F1ED}\u{200B}\u{1F1EE} ",
+                "\u{1F1F9}\u{200B}\u{1F1ED}\u{200B}\u{1F1EA}\u{200B}\u{1F1F7}\u{200B}\u{1F1EA} 42#!"
+            )
+        );
+        // only adjacent indicators are separated
+        assert_eq!(
+            Transform::RegionalIndicators.apply("Zürich"),
+            "\u{1F1FF}\u{FC}\u{1F1F7}\u{200B}\u{1F1EE}\u{200B}\u{1F1E8}\u{200B}\u{1F1ED}"
+        );
+    }
+
+    #[test]
+    fn squared_letters() {
+        assert_eq!(
+            Transform::Squared.apply("Az ß"),
+            "\u{1F130}\u{1F149} \u{DF}"
+        );
+        assert_eq!(
+            Transform::NegativeSquared.apply("Az ß"),
+            "\u{1F170}\u{1F189} \u{DF}"
+        );
+    }
+
+    #[test]
+    fn keycaps() {
+        assert_eq!(
+            Transform::Keycaps.apply("42#*!"),
+            "4\u{FE0F}\u{20E3}2\u{FE0F}\u{20E3}#\u{FE0F}\u{20E3}*\u{FE0F}\u{20E3}!"
+        );
+        // only ASCII digits have keycaps
+        assert_eq!(Transform::Keycaps.apply("\u{661}x"), "\u{661}x");
+    }
+
+    #[test]
+    fn clap() {
+        assert_eq!(
+            Transform::Clap.apply("Hi there 42#!"),
+            "Hi \u{1F44F} there \u{1F44F} 42#!"
+        );
+        assert_eq!(
+            Transform::Clap.apply("  Grüße\tan \n alle  "),
+            "Grüße \u{1F44F} an \u{1F44F} alle"
+        );
+        assert_eq!(Transform::Clap.apply("one"), "one");
+    }
+
+    #[test]
+    fn empty_text() {
+        for transform in Transform::ALL {
+            assert_eq!(transform.apply(""), "", "{:?}", transform);
+        }
+        assert_eq!(Transform::Squared.apply(" "), " ");
+        // whitespace is not a word to clap between
+        assert_eq!(Transform::Clap.apply(" "), "");
+    }
+}
@@ -1,4 +1,11 @@
-use crate::{config::EmojiOrdering, google_ordering::GOOGLE_ORDERING};
+use std::collections::HashSet;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::config::{EmojiOrdering, Presentation};
+use crate::google_ordering::GOOGLE_ORDERING;
+use once_cell::sync::Lazy;
 
 pub(crate) fn all_emojis(
     emoji_ordering: EmojiOrdering,
@@ -77,7 +84,7 @@ pub(crate) fn google_emojis_in_group(
     return google_emojis_internal(&GOOGLE_ORDERING[start..start + end]);
 }
 
-enum Either<Left, Right> {
+pub(crate) enum Either<Left, Right> {
     Left(Left),
     Right(Right),
 }
@@ -130,3 +137,404 @@ impl<'a> Iterator for Lines<'a> {
         return Some(line);
     }
 }
+
+/// The skin tones a single person can have, including the default.
+pub(crate) const PERSON_SKIN_TONES: [emojis::SkinTone; 6] = [
+    emojis::SkinTone::Default,
+    emojis::SkinTone::Light,
+    emojis::SkinTone::MediumLight,
+    emojis::SkinTone::Medium,
+    emojis::SkinTone::MediumDark,
+    emojis::SkinTone::Dark,
+];
+
+/// Whether the emoji shows more than one person with their own skin tone.
+pub(crate) fn is_multi_person(emoji: &emojis::Emoji) -> bool {
+    emoji
+        .skin_tones()
+        .is_some_and(|skin_tones| skin_tones.count() > 6)
+}
+
+/// Combines the skin tones of two persons into the skin tone of a multi-person emoji.
+/// Returns `None` if only one person has the default skin tone, as there is no such emoji.
+pub(crate) fn compose_skin_tone(
+    first: emojis::SkinTone,
+    second: emojis::SkinTone,
+) -> Option<emojis::SkinTone> {
+    use emojis::SkinTone::*;
+    let skin_tone = match (first, second) {
+        (first, second) if first == second => first,
+        (Default, _) | (_, Default) => return None,
+        (Light, MediumLight) => LightAndMediumLight,
+        (Light, Medium) => LightAndMedium,
+        (Light, MediumDark) => LightAndMediumDark,
+        (Light, Dark) => LightAndDark,
+        (MediumLight, Light) => MediumLightAndLight,
+        (MediumLight, Medium) => MediumLightAndMedium,
+        (MediumLight, MediumDark) => MediumLightAndMediumDark,
+        (MediumLight, Dark) => MediumLightAndDark,
+        (Medium, Light) => MediumAndLight,
+        (Medium, MediumLight) => MediumAndMediumLight,
+        (Medium, MediumDark) => MediumAndMediumDark,
+        (Medium, Dark) => MediumAndDark,
+        (MediumDark, Light) => MediumDarkAndLight,
+        (MediumDark, MediumLight) => MediumDarkAndMediumLight,
+        (MediumDark, Medium) => MediumDarkAndMedium,
+        (MediumDark, Dark) => MediumDarkAndDark,
+        (Dark, Light) => DarkAndLight,
+        (Dark, MediumLight) => DarkAndMediumLight,
+        (Dark, Medium) => DarkAndMedium,
+        (Dark, MediumDark) => DarkAndMediumDark,
+        _ => return None,
+    };
+    Some(skin_tone)
+}
+
+/// Splits the skin tone of a multi-person emoji into the skin tones of both persons.
+pub(crate) fn split_skin_tone(skin_tone: emojis::SkinTone) -> (emojis::SkinTone, emojis::SkinTone) {
+    PERSON_SKIN_TONES
+        .iter()
+        .flat_map(|&first| PERSON_SKIN_TONES.iter().map(move |&second| (first, second)))
+        .find(|&(first, second)| compose_skin_tone(first, second) == Some(skin_tone))
+        .unwrap_or((emojis::SkinTone::Default, emojis::SkinTone::Default))
+}
+
+/// Name for files of `emoji`: the shortcode of its default skin tone, followed by its skin tone.
+pub(crate) fn file_stem(emoji: &emojis::Emoji) -> String {
+    let base = emoji
+        .skin_tones()
+        .and_then(|mut skin_tones| skin_tones.next())
+        .unwrap_or(emoji);
+    let mut stem = base.shortcode().unwrap_or("emoji").to_string();
+    if let Some(skin_tone) = emoji
+        .skin_tone()
+        .filter(|&t| t != emojis::SkinTone::Default)
+    {
+        for c in format!("{:?}", skin_tone).chars() {
+            if c.is_uppercase() {
+                stem.push('-');
+            }
+            stem.push(c.to_ascii_lowercase());
+        }
+    }
+    stem
+}
+
+/// Writes `contents` to `{stem}.{extension}` in `dir`, or to `{stem}-{n}.{extension}`
+/// with the first free `n` if the file already exists.
+pub(crate) fn create_new_file(
+    dir: &Path,
+    stem: &str,
+    extension: &str,
+    contents: &[u8],
+) -> io::Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+    let mut n = 0;
+    loop {
+        let path = match n {
+            0 => dir.join(format!("{}.{}", stem, extension)),
+            n => dir.join(format!("{}-{}.{}", stem, n, extension)),
+        };
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(mut file) => {
+                file.write_all(contents)?;
+                return Ok(path);
+            }
+            Err(err) if err.kind() == io::ErrorKind::AlreadyExists => n += 1,
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+const ZWJ: char = '\u{200d}';
+
+/// Joins the components of a ZWJ sequence, e.g. 👩 ❤️ 👨 to 👩‍❤️‍👨.
+pub(crate) fn zwj_join(components: &[&'static emojis::Emoji]) -> String {
+    let mut sequence = String::new();
+    for (idx, component) in components.iter().enumerate() {
+        if idx > 0 {
+            sequence.push(ZWJ);
+        }
+        sequence.push_str(component.as_str());
+    }
+    sequence
+}
+
+/// The RGI emoji composed of `components`, if there is one.
+pub(crate) fn zwj_emoji(components: &[&'static emojis::Emoji]) -> Option<&'static emojis::Emoji> {
+    if components.len() < 2 {
+        return None;
+    }
+    emojis::get(&zwj_join(components))
+}
+
+/// Whether `next` can be appended to `components` on the way to an RGI ZWJ sequence.
+pub(crate) fn zwj_extends(
+    components: &[&'static emojis::Emoji],
+    next: &'static emojis::Emoji,
+) -> bool {
+    let mut prefix = zwj_join(components);
+    if !components.is_empty() {
+        prefix.push(ZWJ);
+    }
+    prefix.push_str(next.as_str());
+    if components.is_empty() {
+        prefix.push(ZWJ);
+    } else if emojis::get(&prefix).is_some() {
+        return true;
+    } else {
+        prefix.push(ZWJ);
+    }
+    unicode_emojis().any(|emoji| emoji.as_str().starts_with(&prefix))
+}
+
+/// Splits a ZWJ sequence into its components.
+/// Returns an empty list if the emoji is not a ZWJ sequence.
+pub(crate) fn zwj_components(emoji: &'static emojis::Emoji) -> Vec<&'static emojis::Emoji> {
+    let emoji_str = emoji.as_str();
+    if !emoji_str.contains(ZWJ) {
+        return Vec::new();
+    }
+    emoji_str
+        .split(ZWJ)
+        .map(emojis::get)
+        .collect::<Option<Vec<_>>>()
+        .unwrap_or_default()
+}
+
+const VS_TEXT: char = '\u{fe0e}';
+const VS_EMOJI: char = '\u{fe0f}';
+const KEYCAP: char = '\u{20e3}';
+
+/// Characters which are shown as text unless followed by `U+FE0F`, e.g. ❤, ™ or keycap digits.
+static TEXT_DEFAULT: Lazy<HashSet<char>> = Lazy::new(|| {
+    unicode_emojis()
+        .flat_map(|emoji| {
+            let chars: Vec<char> = emoji.as_str().chars().collect();
+            chars
+                .windows(2)
+                .filter(|pair| pair[1] == VS_EMOJI)
+                .map(|pair| pair[0])
+                .collect::<Vec<_>>()
+        })
+        .collect()
+});
+
+/// Replaces the variation selectors of `text` according to `presentation`.
+/// Other text, e.g. of snippets, is kept as it is.
+pub(crate) fn with_presentation(text: &str, presentation: Presentation) -> String {
+    if presentation == Presentation::AsListed {
+        return text.to_string();
+    }
+    let mut output = String::with_capacity(text.len() + 3);
+    let mut chars = text.chars().peekable();
+    let mut previous = None;
+    while let Some(c) = chars.next() {
+        output.push(c);
+        if c == VS_EMOJI || c == VS_TEXT {
+            continue;
+        }
+        let selector = chars.next_if(|&next| next == VS_EMOJI || next == VS_TEXT);
+        let next = chars.peek().copied();
+        // digits, `#` and `*` are only emojis as part of a keycap
+        let has_variants = TEXT_DEFAULT.contains(&c) && (!c.is_ascii() || next == Some(KEYCAP));
+        let modified = next.is_some_and(|next| ('\u{1f3fb}'..='\u{1f3ff}').contains(&next));
+        let standalone =
+            previous != Some(ZWJ) && !modified && next != Some(ZWJ) && next != Some(KEYCAP);
+        let selector = match presentation {
+            Presentation::AsListed => selector,
+            Presentation::Emoji if has_variants && !modified => Some(VS_EMOJI),
+            Presentation::Emoji => selector.filter(|&selector| selector != VS_TEXT),
+            Presentation::Text if has_variants && standalone => Some(VS_TEXT),
+            Presentation::Text => selector,
+            Presentation::Minimal => selector.filter(|_| previous != Some(ZWJ)),
+        };
+        output.extend(selector);
+        previous = Some(c);
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn emoji(emoji: &str) -> &'static emojis::Emoji {
+        emojis::get(emoji).unwrap()
+    }
+
+    #[test]
+    fn skin_tone_round_trips() {
+        use emojis::SkinTone::*;
+        for base in ["🧑‍🤝‍🧑", "👫", "💏"] {
+            let base = emoji(base);
+            assert!(is_multi_person(base), "{}", base);
+            for first in PERSON_SKIN_TONES {
+                for second in PERSON_SKIN_TONES {
+                    let Some(skin_tone) = compose_skin_tone(first, second) else {
+                        // only one person with the default skin tone
+                        assert_ne!(first == Default, second == Default);
+                        continue;
+                    };
+                    assert!(
+                        base.with_skin_tone(skin_tone).is_some(),
+                        "{} {:?}",
+                        base,
+                        skin_tone
+                    );
+                    assert_eq!(split_skin_tone(skin_tone), (first, second));
+                }
+            }
+        }
+        assert_eq!(
+            compose_skin_tone(Light, Dark).and_then(|t| emoji("🧑‍🤝‍🧑").with_skin_tone(t)),
+            Some(emoji("🧑🏻‍🤝‍🧑🏿"))
+        );
+        assert_eq!(
+            compose_skin_tone(MediumDark, MediumLight).and_then(|t| emoji("💏").with_skin_tone(t)),
+            Some(emoji("🧑🏾‍❤️‍💋‍🧑🏼"))
+        );
+
+        // emojis without skin tones are left alone
+        let dog = emoji("🐶");
+        assert!(!is_multi_person(dog));
+        assert!(!is_multi_person(emoji("👋")));
+        assert_eq!(dog.skin_tone(), None);
+        assert_eq!(
+            compose_skin_tone(Medium, Medium).and_then(|t| dog.with_skin_tone(t)),
+            None
+        );
+        assert_eq!(split_skin_tone(Default), (Default, Default));
+    }
+
+    #[test]
+    fn zwj_sequences() {
+        let woman = emoji("👩");
+        let heart = emoji("❤️");
+        let man = emoji("👨");
+        let couple = emoji("👩‍❤️‍👨");
+        assert_eq!(zwj_join(&[]), "");
+        assert_eq!(zwj_join(&[woman]), "👩");
+        assert_eq!(zwj_join(&[woman, heart, man]), couple.as_str());
+        assert_eq!(zwj_emoji(&[woman, heart, man]), Some(couple));
+        // a single component is not a sequence, even though it is an emoji
+        assert_eq!(zwj_emoji(&[woman]), None);
+        assert_eq!(zwj_emoji(&[man, heart, man, heart]), None);
+
+        assert_eq!(zwj_components(couple), [woman, heart, man]);
+        assert!(zwj_components(woman).is_empty());
+        assert_eq!(zwj_components(emoji("🏳️‍🌈")), [emoji("🏳️"), emoji("🌈")]);
+    }
+
+    #[test]
+    fn zwj_prefixes() {
+        let woman = emoji("👩");
+        let heart = emoji("❤️");
+        assert!(zwj_extends(&[], woman));
+        assert!(zwj_extends(&[woman], heart));
+        // completes an RGI sequence
+        assert!(zwj_extends(&[woman, heart], emoji("👨")));
+        assert!(!zwj_extends(&[], emoji("🐶")));
+        assert!(!zwj_extends(&[woman], emoji("🐶")));
+    }
+
+    #[test]
+    fn file_stems() {
+        assert_eq!(file_stem(emoji("👋")), "wave");
+        assert_eq!(file_stem(emoji("👋🏽")), "wave-medium");
+        assert_eq!(
+            file_stem(emoji("🧑🏻‍🤝‍🧑🏿")),
+            "people_holding_hands-light-and-dark"
+        );
+    }
+
+    #[test]
+    fn presentations() {
+        // expected for `AsListed`, `Emoji`, `Text` and `Minimal`
+        let table = [
+            (
+                "\u{2764}",
+                [
+                    "\u{2764}",
+                    "\u{2764}\u{FE0F}",
+                    "\u{2764}\u{FE0E}",
+                    "\u{2764}",
+                ],
+            ),
+            (
+                "\u{2764}\u{FE0F}",
+                [
+                    "\u{2764}\u{FE0F}",
+                    "\u{2764}\u{FE0F}",
+                    "\u{2764}\u{FE0E}",
+                    "\u{2764}\u{FE0F}",
+                ],
+            ),
+            (
+                "\u{2764}\u{FE0E}",
+                [
+                    "\u{2764}\u{FE0E}",
+                    "\u{2764}\u{FE0F}",
+                    "\u{2764}\u{FE0E}",
+                    "\u{2764}\u{FE0E}",
+                ],
+            ),
+            // every sequence keeps the selector of its first character
+            (
+                "\u{2764}\u{FE0F}\u{2764}\u{FE0F}",
+                [
+                    "\u{2764}\u{FE0F}\u{2764}\u{FE0F}",
+                    "\u{2764}\u{FE0F}\u{2764}\u{FE0F}",
+                    "\u{2764}\u{FE0E}\u{2764}\u{FE0E}",
+                    "\u{2764}\u{FE0F}\u{2764}\u{FE0F}",
+                ],
+            ),
+            (
+                "\u{2122}",
+                [
+                    "\u{2122}",
+                    "\u{2122}\u{FE0F}",
+                    "\u{2122}\u{FE0E}",
+                    "\u{2122}",
+                ],
+            ),
+            // keycaps
+            ("1\u{FE0F}\u{20E3}", ["1\u{FE0F}\u{20E3}"; 4]),
+            (
+                "1\u{20E3}",
+                ["1\u{20E3}", "1\u{FE0F}\u{20E3}", "1\u{20E3}", "1\u{20E3}"],
+            ),
+            // ZWJ sequences are never shown as text
+            (
+                "\u{2764}\u{FE0F}\u{200D}\u{1F525}",
+                ["\u{2764}\u{FE0F}\u{200D}\u{1F525}"; 4],
+            ),
+            (
+                "\u{1F441}\u{FE0F}\u{200D}\u{1F5E8}\u{FE0F}",
+                [
+                    "\u{1F441}\u{FE0F}\u{200D}\u{1F5E8}\u{FE0F}",
+                    "\u{1F441}\u{FE0F}\u{200D}\u{1F5E8}\u{FE0F}",
+                    "\u{1F441}\u{FE0F}\u{200D}\u{1F5E8}\u{FE0F}",
+                    "\u{1F441}\u{FE0F}\u{200D}\u{1F5E8}",
+                ],
+            ),
+            // skin tones
+            ("\u{261D}\u{1F3FB}", ["\u{261D}\u{1F3FB}"; 4]),
+            ("\u{1F600}", ["\u{1F600}"; 4]),
+            // digits and symbols are only emojis in keycaps
+            ("a1#", ["a1#"; 4]),
+            ("", [""; 4]),
+        ];
+        for (text, expected) in table {
+            for (presentation, expected) in Presentation::ALL.into_iter().zip(expected) {
+                assert_eq!(
+                    with_presentation(text, presentation),
+                    expected,
+                    "{:?} {:?}",
+                    text,
+                    presentation
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,236 @@
+// SPDX-License-Identifier: MPL-2.0
+// 2024 - Dominic Gerhauser and contributors
+use std::error::Error;
+use std::fmt::Write as _;
+use std::io::Write as _;
+use std::os::fd::AsFd;
+use std::time::Duration;
+
+use wayland_client::globals::{registry_queue_init, GlobalListContents};
+use wayland_client::protocol::{wl_keyboard, wl_registry, wl_seat};
+use wayland_client::{delegate_noop, Connection, Dispatch, QueueHandle};
+
+use protocol::zwp_virtual_keyboard_manager_v1::ZwpVirtualKeyboardManagerV1;
+use protocol::zwp_virtual_keyboard_v1::ZwpVirtualKeyboardV1;
+
+/// Client bindings of the virtual keyboard protocol, generated like in `wayland-protocols-misc`.
+#[allow(dead_code, non_camel_case_types, unused_unsafe, unused_variables)]
+#[allow(non_upper_case_globals, non_snake_case, unused_imports)]
+#[allow(missing_docs, clippy::all)]
+mod protocol {
+    use wayland_client;
+    use wayland_client::protocol::*;
+
+    pub mod __interfaces {
+        use wayland_client::protocol::__interfaces::*;
+        wayland_scanner::generate_interfaces!("protocols/virtual-keyboard-unstable-v1.xml");
+    }
+    use self::__interfaces::*;
+
+    wayland_scanner::generate_client_code!("protocols/virtual-keyboard-unstable-v1.xml");
+}
+
+/// Time for the focused application to get the keyboard focus back after the popup closed.
+pub const DELAY: Duration = Duration::from_millis(150);
+
+struct State;
+
+impl Dispatch<wl_registry::WlRegistry, GlobalListContents> for State {
+    fn event(
+        _: &mut Self,
+        _: &wl_registry::WlRegistry,
+        _: wl_registry::Event,
+        _: &GlobalListContents,
+        _: &Connection,
+        _: &QueueHandle<Self>,
+    ) {
+    }
+}
+
+delegate_noop!(State: ignore wl_seat::WlSeat);
+delegate_noop!(State: ZwpVirtualKeyboardManagerV1);
+delegate_noop!(State: ZwpVirtualKeyboardV1);
+
+/// Types `text` into the focused application with a virtual keyboard, like `wtype`.
+/// Fails if the compositor does not support the virtual keyboard protocol.
+pub(crate) fn type_text(text: &str) -> Result<(), Box<dyn Error>> {
+    let (symbols, keycodes) = text_keys(text);
+    with_keyboard(&keymap(&symbols), |keyboard| {
+        for (time, keycode) in keycodes.into_iter().enumerate() {
+            let time = time as u32 * 2;
+            keyboard.key(time, keycode, wl_keyboard::KeyState::Pressed as u32);
+            keyboard.key(time + 1, keycode, wl_keyboard::KeyState::Released as u32);
+        }
+    })
+}
+
+/// Presses a key chord like `Ctrl+V` or `Ctrl+Shift+V` in the focused application.
+pub(crate) fn press_chord(chord: &str) -> Result<(), Box<dyn Error>> {
+    let (modifiers, key) = parse_chord(chord)?;
+    with_keyboard(&keymap(&[key]), |keyboard| {
+        keyboard.modifiers(modifiers, 0, 0, 0);
+        keyboard.key(0, 1, wl_keyboard::KeyState::Pressed as u32);
+        keyboard.key(1, 1, wl_keyboard::KeyState::Released as u32);
+        keyboard.modifiers(0, 0, 0, 0);
+    })
+}
+
+/// Keysym names of the keys which can be pressed besides letters, digits and function keys.
+const NAMED_KEYS: [&str; 17] = [
+    "Insert",
+    "Delete",
+    "Return",
+    "Tab",
+    "space",
+    "BackSpace",
+    "Escape",
+    "Home",
+    "End",
+    "Page_Up",
+    "Page_Down",
+    "Left",
+    "Right",
+    "Up",
+    "Down",
+    "Menu",
+    "Print",
+];
+
+/// Splits a key chord into the xkb modifier mask and the keysym name of its key.
+pub(crate) fn parse_chord(chord: &str) -> Result<(u32, String), String> {
+    let mut parts: Vec<&str> = chord.split('+').map(str::trim).collect();
+    let key = parts.pop().unwrap_or_default();
+    let Some(key) = keysym(key) else {
+        return Err(format!("invalid key {:?} in {:?}", key, chord));
+    };
+    let mut modifiers = 0;
+    for modifier in parts {
+        // the modifier indices of the `complete` xkb compatibility map
+        modifiers |= match modifier.to_ascii_lowercase().as_str() {
+            "shift" => 1,
+            "ctrl" | "control" => 1 << 2,
+            "alt" => 1 << 3,
+            "super" | "logo" | "meta" => 1 << 6,
+            _ => return Err(format!("invalid modifier {:?} in {:?}", modifier, chord)),
+        };
+    }
+    Ok((modifiers, key))
+}
+
+/// The keysym name of `key`, ignoring its case.
+fn keysym(key: &str) -> Option<String> {
+    if key.len() == 1 && key.chars().all(|c| c.is_ascii_alphanumeric()) {
+        // letters are given as lowercase keysyms, shift is a modifier of its own
+        return Some(key.to_ascii_lowercase());
+    }
+    let function_key = key
+        .strip_prefix(['F', 'f'])
+        .and_then(|n| n.parse::<u8>().ok())
+        .filter(|n| (1..=35).contains(n));
+    if let Some(n) = function_key {
+        return Some(format!("F{}", n));
+    }
+    NAMED_KEYS
+        .iter()
+        .find(|name| name.eq_ignore_ascii_case(key))
+        .map(|name| name.to_string())
+}
+
+/// Uploads `keymap` to a new virtual keyboard and sends the keys of `press`.
+fn with_keyboard(
+    keymap: &str,
+    press: impl FnOnce(&ZwpVirtualKeyboardV1),
+) -> Result<(), Box<dyn Error>> {
+    let connection = Connection::connect_to_env()?;
+    let (globals, mut queue) = registry_queue_init::<State>(&connection)?;
+    let qh = queue.handle();
+    let seat: wl_seat::WlSeat = globals.bind(&qh, 1..=1, ())?;
+    let manager: ZwpVirtualKeyboardManagerV1 = globals.bind(&qh, 1..=1, ())?;
+    let keyboard = manager.create_virtual_keyboard(&seat, &qh, ());
+
+    let mut keymap_file = tempfile::tempfile()?;
+    keymap_file.write_all(keymap.as_bytes())?;
+    keymap_file.write_all(&[0])?;
+    keyboard.keymap(
+        wl_keyboard::KeymapFormat::XkbV1 as u32,
+        keymap_file.as_fd(),
+        keymap.len() as u32 + 1,
+    );
+    queue.roundtrip(&mut State)?;
+
+    press(&keyboard);
+    queue.roundtrip(&mut State)?;
+    keyboard.destroy();
+    manager.destroy();
+    connection.flush()?;
+    Ok(())
+}
+
+/// The keysyms of the characters of `text` and the keycodes to type it.
+fn text_keys(text: &str) -> (Vec<String>, Vec<u32>) {
+    let mut chars: Vec<char> = Vec::new();
+    let keycodes = text
+        .chars()
+        .map(|c| {
+            let idx = chars.iter().position(|&k| k == c).unwrap_or_else(|| {
+                chars.push(c);
+                chars.len() - 1
+            });
+            idx as u32 + 1
+        })
+        .collect();
+    let symbols = chars
+        .into_iter()
+        .map(|c| format!("U{:04X}", u32::from(c)))
+        .collect();
+    (symbols, keycodes)
+}
+
+/// A keymap with one key for every keysym in `symbols`, with the keycodes counted from 1.
+fn keymap(symbols: &[String]) -> String {
+    // evdev keycodes are offset by 8 in xkb
+    let mut keymap = String::from("xkb_keymap {\nxkb_keycodes \"(unnamed)\" {\nminimum = 8;\n");
+    let _ = writeln!(keymap, "maximum = {};", symbols.len() + 9);
+    for idx in 1..=symbols.len() {
+        let _ = writeln!(keymap, "<K{idx}> = {};", idx + 8);
+    }
+    keymap.push_str("};\n");
+    keymap.push_str("xkb_types \"(unnamed)\" { include \"complete\" };\n");
+    keymap.push_str("xkb_compatibility \"(unnamed)\" { include \"complete\" };\n");
+    keymap.push_str("xkb_symbols \"(unnamed)\" {\n");
+    for (idx, symbol) in symbols.iter().enumerate() {
+        let _ = writeln!(keymap, "key <K{}> {{[{}]}};", idx + 1, symbol);
+    }
+    keymap.push_str("};\n};\n");
+    keymap
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chords() {
+        let table = [
+            ("Ctrl+V", Some((4, "v"))),
+            ("Ctrl+Shift+V", Some((5, "v"))),
+            ("ctrl+shift+v", Some((5, "v"))),
+            (" Ctrl + Shift + V ", Some((5, "v"))),
+            ("CONTROL+v", Some((4, "v"))),
+            ("Shift+insert", Some((1, "Insert"))),
+            ("Super+Alt+f12", Some((72, "F12"))),
+            ("V", Some((0, "v"))),
+            ("Ctrl+Paste", None),
+            ("Ctrl+F36", None),
+            ("Ctrl+?", None),
+            ("Hyper+V", None),
+            ("Ctrl+", None),
+            ("Ctrl+Shift", None),
+            ("", None),
+        ];
+        for (chord, expected) in table {
+            let expected = expected.map(|(modifiers, key)| (modifiers, key.to_string()));
+            assert_eq!(parse_chord(chord).ok(), expected, "{:?}", chord);
+        }
+    }
+}
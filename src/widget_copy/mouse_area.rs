@@ -10,7 +10,15 @@ use cosmic::iced_core::overlay;
 use cosmic::iced_core::renderer;
 use cosmic::iced_core::touch;
 use cosmic::iced_core::widget::{tree, Operation, Tree};
+use cosmic::iced_core::window;
 use cosmic::iced_core::{Clipboard, Element, Layout, Length, Rectangle, Shell, Size, Widget};
+use std::time::{Duration, Instant};
+
+/// How long the left button has to be held for a long press.
+const LONG_PRESS: Duration = Duration::from_millis(500);
+
+/// How far the cursor has to move with the left button held to start a drag.
+const DRAG_DISTANCE: f32 = 8.0;
 
 /// Emit messages on mouse events.
 #[allow(missing_debug_implementations)]
@@ -19,6 +27,7 @@ pub struct MouseArea<'a, Message, Theme = cosmic::Theme, Renderer = cosmic::iced
     on_drag: Option<Message>,
     on_press: Option<Message>,
     on_release: Option<Message>,
+    on_long_press: Option<Message>,
     on_right_press: Option<Message>,
     on_right_release: Option<Message>,
     on_middle_press: Option<Message>,
@@ -50,6 +59,14 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
         self
     }
 
+    /// The message to emit when the left button is held over the area for a while.
+    /// The content does not receive the click in this case.
+    #[must_use]
+    pub fn on_long_press(mut self, message: Message) -> Self {
+        self.on_long_press = Some(message);
+        self
+    }
+
     /// The message to emit on a right button press.
     #[must_use]
     pub fn on_right_press(mut self, message: Message) -> Self {
@@ -106,6 +123,8 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
 #[derive(Default)]
 struct State {
     drag_initiated: Option<Point>,
+    pressed_at: Option<Instant>,
+    long_pressed: bool,
     is_hovered: bool,
 }
 
@@ -117,6 +136,7 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
             on_drag: None,
             on_press: None,
             on_release: None,
+            on_long_press: None,
             on_right_press: None,
             on_right_release: None,
             on_middle_press: None,
@@ -188,6 +208,103 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
+        // the content captures the press, so drags are tracked before it sees the event
+        if let Some(message) = self.on_drag.as_ref() {
+            let state = tree.state.downcast_mut::<State>();
+            let mut dragged = false;
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                    state.drag_initiated = cursor.position_over(layout.bounds());
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                    state.drag_initiated = None;
+                }
+                Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                    dragged = state.drag_initiated.zip(cursor.position()).is_some_and(
+                        |(source, position)| position.distance(source) > DRAG_DISTANCE,
+                    );
+                }
+                _ => {}
+            }
+            if dragged {
+                state.drag_initiated = None;
+                state.pressed_at = None;
+                // like after a long press, the content must not emit a click
+                let _ = self.content.as_widget_mut().on_event(
+                    &mut tree.children[0],
+                    Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+                    layout,
+                    mouse::Cursor::Unavailable,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                );
+                shell.publish(message.clone());
+                return event::Status::Captured;
+            }
+        }
+
+        if let Some(message) = self.on_long_press.as_ref() {
+            let state = tree.state.downcast_mut::<State>();
+            let mut released = false;
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                    state.long_pressed = false;
+                    state.pressed_at = cursor.is_over(layout.bounds()).then(Instant::now);
+                    if let Some(pressed_at) = state.pressed_at {
+                        // wakes up the event loop, even if the cursor does not move
+                        shell.request_redraw(window::RedrawRequest::At(pressed_at + LONG_PRESS));
+                    }
+                }
+                Event::Window(_, window::Event::RedrawRequested(now)) => {
+                    if let Some(pressed_at) = state.pressed_at {
+                        if now < pressed_at + LONG_PRESS {
+                            shell
+                                .request_redraw(window::RedrawRequest::At(pressed_at + LONG_PRESS));
+                        } else {
+                            state.pressed_at = None;
+                            state.long_pressed = cursor.is_over(layout.bounds());
+                            if state.long_pressed {
+                                shell.publish(message.clone());
+                            }
+                        }
+                    }
+                }
+                Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                    if !cursor.is_over(layout.bounds()) {
+                        state.pressed_at = None;
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                    state.pressed_at = None;
+                    released = std::mem::take(&mut state.long_pressed);
+                }
+                _ => {}
+            }
+            if released {
+                // the content still sees the release, but without a cursor,
+                // so a button resets its pressed state without emitting a click
+                let _ = self.content.as_widget_mut().on_event(
+                    &mut tree.children[0],
+                    event,
+                    layout,
+                    mouse::Cursor::Unavailable,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                );
+                return event::Status::Captured;
+            }
+        }
+
         if let event::Status::Captured = self.content.as_widget_mut().on_event(
             &mut tree.children[0],
             event.clone(),
@@ -315,7 +432,6 @@ fn update<Message: Clone, Theme, Renderer>(
         if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
         | Event::Touch(touch::Event::FingerPressed { .. }) = event
         {
-            state.drag_initiated = cursor.position();
             shell.publish(message.clone());
 
             return event::Status::Captured;
@@ -326,7 +442,6 @@ fn update<Message: Clone, Theme, Renderer>(
         if let Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
         | Event::Touch(touch::Event::FingerLifted { .. }) = event
         {
-            state.drag_initiated = None;
             shell.publish(message.clone());
 
             return event::Status::Captured;
@@ -365,22 +480,5 @@ fn update<Message: Clone, Theme, Renderer>(
         }
     }
 
-    if state.drag_initiated.is_none() && widget.on_drag.is_some() {
-        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-        | Event::Touch(touch::Event::FingerPressed { .. }) = event
-        {
-            state.drag_initiated = cursor.position();
-        }
-    } else if let Some((message, drag_source)) = widget.on_drag.as_ref().zip(state.drag_initiated) {
-        if let Some(position) = cursor.position() {
-            if position.distance(drag_source) > 1.0 {
-                state.drag_initiated = None;
-                shell.publish(message.clone());
-
-                return event::Status::Captured;
-            }
-        }
-    }
-
     event::Status::Ignored
 }